@@ -29,6 +29,7 @@
 //! - prefix=...
 //! ###
 //! - include=\[...\] - generate getter/setters only for the listed fields.
+//!
 //! For example:
 //! ```rust,ignore
 //! #[derive(trl)]
@@ -40,6 +41,7 @@
 //! Would generate getters/setters for `b` and `c` fields.
 //!
 //! - exclude=\[...\] - generate getters/setters for all fields except the listed.
+//!
 //! For example
 //!
 //! ```rust,ignore
@@ -52,9 +54,11 @@
 //! Would generate getters/setters only for the `c` field.
 //!
 //! - pub - include public fields.
+//!
 //! By default public fields are ignored, but you can specify the `pub` argument to generate getters/setters for them too
 //!
 //! - prefix=... - generates getters/setters with specified prefix.
+//!
 //! For example
 //! ```rust,ignore
 //! #[derive(trl)]
@@ -64,6 +68,7 @@
 //! ```
 //! Would generate getters:
 //! - `get_id()`, `get_name()`
+//!
 //! and setters:
 //! - `set_id()`, `set_name()`
 //!
@@ -126,7 +131,12 @@
 //! ```
 //!
 
+extern crate self as trl;
+
+mod value;
+
 pub use trl_codegen::*;
+pub use value::{FromValue, FromValueError, MistypedField, ToValue, Value};
 
 pub mod prelude {
     //! Module `prelude` reexports all commonly used attributes.
@@ -148,14 +158,42 @@ pub mod prelude {
     //!
     //! This limitation exists due to the current implementation.
     pub use crate::constructor;
+    pub use crate::display;
+    pub use crate::eq;
     pub use crate::getters;
+    pub use crate::hash;
+    pub use crate::ord;
+    pub use crate::partial;
+    pub use crate::parts;
     pub use crate::setters;
     pub use crate::trl;
+    pub use crate::trl_trait;
+    pub use crate::value;
+    pub use crate::{FromValue, ToValue, Value};
 }
 
 #[cfg(test)]
+#[allow(
+    dead_code,
+    unused_variables,
+    clippy::cmp_owned,
+    clippy::comparison_to_empty
+)]
 mod tests {
-    use trl_codegen::{constructor, getters, setters, trl};
+    use crate::{FromValue, ToValue, Value};
+    use std::cell::{Cell, RefCell};
+    use std::collections::{BTreeMap, HashMap, HashSet};
+    use std::fmt::{self, Debug};
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::rc::Rc;
+    use std::sync::atomic::{AtomicBool, AtomicU64};
+    use std::sync::{Arc, Mutex, RwLock};
+    use std::task::{Context, Poll, Waker};
+    use trl_codegen::{
+        constructor, display, eq, getters, hash, ord, partial, parts, setters, trl, trl_trait,
+        value,
+    };
 
     #[derive(Default, trl)]
     #[getters]
@@ -244,4 +282,1074 @@ mod tests {
     fn constructor_test() {
         let user = ConstructorUser::new(0, String::new(), String::new(), 3);
     }
+
+    #[derive(Debug, PartialEq, trl)]
+    #[value]
+    struct ValueAddress {
+        city: String,
+        zip: Option<u32>,
+    }
+
+    #[derive(Debug, PartialEq, trl)]
+    #[value]
+    struct ValueUser {
+        id: u64,
+        name: String,
+        tags: Vec<String>,
+        address: ValueAddress,
+    }
+
+    #[test]
+    fn value_round_trip_test() {
+        let user = ValueUser {
+            id: 7,
+            name: String::from("John"),
+            tags: vec![String::from("admin")],
+            address: ValueAddress {
+                city: String::from("Oslo"),
+                zip: None,
+            },
+        };
+
+        let map = user.to_map();
+
+        assert!(map["id"] == Value::UInt(7));
+        assert!(map["name"] == Value::String(String::from("John")));
+        assert!(ValueUser::from_map(&map).unwrap() == user);
+        assert!(ValueUser::from_value(&user.to_value()).unwrap() == user);
+    }
+
+    #[test]
+    fn value_errors_test() {
+        let mut address = BTreeMap::new();
+        address.insert(String::from("city"), Value::Bool(true));
+
+        let mut map = BTreeMap::new();
+        map.insert(String::from("id"), Value::String(String::from("7")));
+        map.insert(String::from("tags"), Value::List(vec![]));
+        map.insert(String::from("address"), Value::Map(address));
+        map.insert(String::from("age"), Value::UInt(30));
+
+        let error = ValueUser::from_map(&map).unwrap_err();
+
+        assert!(error.missing == vec![String::from("name")]);
+        assert!(error.extra == vec![String::from("age")]);
+        assert!(error.mistyped.len() == 2);
+        assert!(error.mistyped[0].field == "id");
+        assert!(error.mistyped[1].field == "address.city");
+        assert!(error.mistyped[1].expected == "string");
+    }
+
+    #[derive(Clone, trl)]
+    #[partial(name = PartialUserPatch, derive(Debug, PartialEq), excludes = [id])]
+    struct PartialUser {
+        id: u64,
+        name: String,
+        email: String,
+        pub phone_number: u64,
+    }
+
+    #[test]
+    fn partial_test() {
+        let mut user = PartialUser {
+            id: 1,
+            name: String::from("John"),
+            email: String::new(),
+            phone_number: 0,
+        };
+
+        let mut patch = PartialUserPatch::default();
+        assert!(patch.is_empty());
+
+        patch.name = Some(String::from("Jane"));
+        patch.phone_number = Some(5);
+        user.apply_patch(patch);
+
+        assert!(user.name == "Jane");
+        assert!(user.phone_number == 5);
+
+        let mut other = user.clone();
+        other.id = 2;
+        other.email = String::from("jane@example.com");
+
+        let diff = user.diff(&other);
+        assert!(
+            diff == PartialUserPatch {
+                name: None,
+                email: Some(String::from("jane@example.com")),
+                phone_number: None,
+            }
+        );
+    }
+
+    #[derive(Default, trl)]
+    #[setters(track)]
+    struct TrackedUser {
+        id: u64,
+        first_name: String,
+        #[dirty]
+        dirty: u8,
+    }
+
+    #[test]
+    fn setters_track_test() {
+        let mut user = TrackedUser::default();
+        assert!(!user.is_dirty());
+
+        user.set_first_name(String::from("John"));
+        assert!(user.is_dirty());
+        assert!(user.is_first_name_dirty());
+        assert!(!user.is_id_dirty());
+        assert!(user.dirty_fields().collect::<Vec<_>>() == vec![TrackedUserField::FirstName]);
+        assert!(TrackedUserField::FirstName.as_str() == "first_name");
+
+        user.clear_dirty();
+        user.set_id(0);
+        assert!(user.is_id_dirty());
+    }
+
+    #[derive(Default, trl)]
+    #[setters(track(eq), prefix = update_)]
+    struct TrackedEqUser {
+        id: u64,
+        #[dirty]
+        changes: u32,
+    }
+
+    #[test]
+    fn setters_track_eq_test() {
+        let mut user = TrackedEqUser::default();
+
+        user.update_id(0);
+        assert!(!user.is_dirty());
+
+        user.update_id(1);
+        assert!(user.is_id_dirty());
+    }
+
+    #[derive(Default, trl)]
+    #[setters(track, excludes = [id])]
+    struct UntrackedUser {
+        id: u64,
+        #[dirty]
+        dirty: u8,
+    }
+
+    #[test]
+    fn setters_track_empty_test() {
+        let user = UntrackedUser::default();
+        assert!(!user.is_dirty());
+        assert!(user.dirty_fields().count() == 0);
+        assert!(user.id == 0);
+    }
+
+    #[derive(Default, trl)]
+    #[setters(notify = on_change, excludes = [log])]
+    struct NotifyUser {
+        id: u64,
+        name: String,
+        log: RefCell<Vec<String>>,
+    }
+
+    impl NotifyUser {
+        fn on_change<T: Debug>(&self, field: &str, old: &T, new: &T) {
+            self.log
+                .borrow_mut()
+                .push(format!("{}: {:?} -> {:?}", field, old, new));
+        }
+    }
+
+    thread_local! {
+        static NOTIFY_LOG: RefCell<Vec<String>> = const { RefCell::new(Vec::new()) };
+    }
+
+    fn record_email(field: &str, old: &String, new: &String) {
+        NOTIFY_LOG.with(|log| {
+            log.borrow_mut()
+                .push(format!("{}: {} -> {}", field, old, new))
+        });
+    }
+
+    #[derive(Default, trl)]
+    struct NotifyFieldUser {
+        #[set(notify = record_email)]
+        email: String,
+    }
+
+    #[test]
+    fn setters_notify_test() {
+        let mut user = NotifyUser::default();
+
+        user.set_id(3);
+        user.set_name(String::from("John"));
+
+        assert!(*user.log.borrow() == vec!["id: 0 -> 3", "name: \"\" -> \"John\""]);
+
+        let mut user = NotifyFieldUser::default();
+        user.set_email(String::from("a@b.c"));
+
+        NOTIFY_LOG.with(|log| assert!(*log.borrow() == vec!["email:  -> a@b.c"]));
+    }
+
+    fn validate_port(port: &u32) -> Result<(), String> {
+        if (1..=65535).contains(port) {
+            Ok(())
+        } else {
+            Err(format!("invalid port {}", port))
+        }
+    }
+
+    fn lowercase(email: String) -> String {
+        email.to_lowercase()
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct EmptyValue(&'static str);
+
+    #[derive(Default, trl)]
+    #[setters(validate = not_empty, error = EmptyValue, excludes = [port, email])]
+    struct ValidatedUser {
+        name: String,
+        surname: String,
+        #[set(validate = validate_port)]
+        port: u32,
+        #[set(map = lowercase)]
+        email: String,
+    }
+
+    impl ValidatedUser {
+        fn not_empty(&self, field: &'static str, value: &str) -> Result<(), EmptyValue> {
+            if value.is_empty() {
+                Err(EmptyValue(field))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    #[test]
+    fn setters_validate_test() {
+        let mut user = ValidatedUser::default();
+
+        assert!(user.set_port(8080).is_ok());
+        assert!(user.set_port(0) == Err(String::from("invalid port 0")));
+        assert!(user.port == 8080);
+
+        assert!(user.set_name(String::new()) == Err(EmptyValue("name")));
+        assert!(user.set_surname(String::from("Doe")).is_ok());
+        assert!(user.surname == "Doe");
+
+        user.set_email(String::from("John@Example.com"));
+        assert!(user.email == "john@example.com");
+    }
+
+    #[allow(dead_code)]
+    #[derive(trl)]
+    #[eq(includes = [id, name])]
+    #[hash(includes = [id, name])]
+    #[ord(by = [name, id desc])]
+    struct CompareUser {
+        id: u64,
+        name: String,
+        visits: u64,
+    }
+
+    #[test]
+    fn compare_test() {
+        let user = |id, name: &str, visits| CompareUser {
+            id,
+            name: String::from(name),
+            visits,
+        };
+
+        assert!(user(1, "John", 0) == user(1, "John", 10));
+        assert!(user(1, "John", 0) != user(2, "John", 0));
+
+        let set = HashSet::from([user(1, "John", 0), user(1, "John", 5)]);
+        assert!(set.len() == 1);
+
+        assert!(user(1, "Adam", 0) < user(1, "John", 0));
+        assert!(user(2, "John", 0) < user(1, "John", 0));
+    }
+
+    #[allow(dead_code)]
+    #[derive(trl)]
+    #[eq(partial, excludes = [label])]
+    #[ord(partial, by = [score desc])]
+    struct PartialCompareScore {
+        score: f64,
+        label: String,
+    }
+
+    #[test]
+    fn partial_compare_test() {
+        let score = |score| PartialCompareScore {
+            score,
+            label: String::new(),
+        };
+
+        assert!(score(1.0) == score(1.0));
+        assert!(score(2.0) < score(1.0));
+        assert!(score(f64::NAN).partial_cmp(&score(1.0)).is_none());
+    }
+
+    fn debug_len(value: &[u8], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<{} bytes>", value.len())
+    }
+
+    #[allow(dead_code)]
+    #[derive(trl)]
+    #[debug(excludes = [cache])]
+    struct DebugUser {
+        name: String,
+        #[redact]
+        password: String,
+        #[redact(hash)]
+        email: String,
+        #[debug(with = debug_len)]
+        avatar: Vec<u8>,
+        #[debug(skip)]
+        session: u64,
+        cache: Vec<u8>,
+    }
+
+    #[test]
+    fn debug_test() {
+        let user = DebugUser {
+            name: String::from("John"),
+            password: String::from("secret"),
+            email: String::from("john@example.com"),
+            avatar: vec![0; 3],
+            session: 42,
+            cache: Vec::new(),
+        };
+
+        let output = format!("{:?}", user);
+
+        assert!(output.starts_with(r#"DebugUser { name: "John", password: "***", email: "#));
+        assert!(output.ends_with(", avatar: <3 bytes>, .. }"));
+        assert!(!output.contains("john@example.com"));
+        assert!(!output.contains("secret"));
+    }
+
+    #[derive(trl)]
+    #[display("#{id:04} {name} <{email}> {{{score:>width$.1}}}")]
+    struct DisplayUser {
+        id: u64,
+        name: String,
+        email: String,
+        score: f64,
+        width: usize,
+    }
+
+    #[test]
+    fn display_test() {
+        let user = DisplayUser {
+            id: 7,
+            name: String::from("John"),
+            email: String::from("john@example.com"),
+            score: 4.25,
+            width: 5,
+        };
+
+        assert!(user.to_string() == "#0007 John <john@example.com> {  4.2}");
+    }
+
+    #[derive(trl)]
+    #[trl(default)]
+    #[constructor]
+    struct DefaultConfig {
+        host: String,
+        #[trl(default = 8080)]
+        port: u16,
+        #[trl(default = vec![String::from("admin")])]
+        roles: Vec<String>,
+        #[trl(default)]
+        retries: u8,
+    }
+
+    #[test]
+    fn default_test() {
+        let config = DefaultConfig::default();
+
+        assert!(config.host.is_empty());
+        assert!(config.port == 8080);
+        assert!(config.roles == vec!["admin"]);
+        assert!(config.retries == 0);
+
+        let config = DefaultConfig::new(String::from("localhost"));
+
+        assert!(config.host == "localhost");
+        assert!(config.port == 8080);
+    }
+
+    #[derive(Default, trl)]
+    #[constructor]
+    struct StdDefaultConfig {
+        host: String,
+        #[trl(default = 8080, group = network)]
+        port: u16,
+    }
+
+    #[test]
+    fn default_with_std_derive_test() {
+        assert!(StdDefaultConfig::default().port == 0);
+
+        let config = StdDefaultConfig::new(String::from("localhost"));
+        assert!(config.host == "localhost" && config.port == 8080);
+    }
+
+    #[derive(trl)]
+    #[parts(includes = [name, id, roles])]
+    struct PartsUser {
+        id: u64,
+        name: String,
+        pub roles: Vec<String>,
+    }
+
+    #[allow(dead_code)]
+    #[derive(trl)]
+    #[parts(excludes = [cache])]
+    struct PartialPartsUser {
+        id: u64,
+        cache: Vec<u8>,
+    }
+
+    #[derive(trl)]
+    #[parts]
+    struct GenericParts<T> {
+        value: T,
+        count: u32,
+    }
+
+    #[test]
+    fn parts_test() {
+        let user = PartsUser::from_parts((String::from("John"), 1, vec![]));
+
+        assert!(user.as_parts() == (&String::from("John"), &1, &vec![]));
+
+        let (name, id, roles) = user.into_parts();
+        assert!(name == "John" && id == 1 && roles.is_empty());
+
+        let user: PartsUser = (name, id, roles).into();
+        let parts: (String, u64, Vec<String>) = user.into();
+        assert!(parts.1 == 1);
+
+        let user = PartialPartsUser {
+            id: 2,
+            cache: vec![1],
+        };
+        let (id,): (u64,) = user.into();
+        assert!(id == 2);
+
+        let parts: GenericParts<&str> = ("a", 1).into();
+        let (value, count): (&str, u32) = parts.into();
+        assert!(value == "a" && count == 1);
+    }
+
+    #[derive(trl)]
+    struct Cache {
+        #[delegate(len, is_empty, get(&self, k: &str) -> Option<&u64>, insert(&mut self, k: String, v: u64) -> Option<u64>)]
+        inner: HashMap<String, u64>,
+    }
+
+    #[derive(trl)]
+    struct Stack {
+        #[deref]
+        items: Vec<u32>,
+    }
+
+    #[test]
+    fn delegate_test() {
+        let mut cache = Cache {
+            inner: HashMap::new(),
+        };
+        assert!(cache.is_empty());
+
+        cache.insert(String::from("a"), 1);
+        assert!(cache.len() == 1);
+        assert!(cache.get("a") == Some(&1));
+
+        let mut stack = Stack { items: vec![1] };
+        stack.push(2);
+        assert!(stack.len() == 2 && stack.items == vec![1, 2]);
+    }
+
+    #[derive(Default, trl)]
+    #[getters]
+    #[setters]
+    struct SharedState {
+        hits: Cell<u32>,
+        names: RefCell<Vec<String>>,
+        queue: Mutex<Vec<u32>>,
+        config: RwLock<String>,
+    }
+
+    #[test]
+    fn interior_mutability_test() {
+        let state = SharedState::default();
+
+        state.set_hits(2);
+        assert!(state.get_hits() == 2);
+
+        state.names_mut().push(String::from("John"));
+        assert!(state.names().len() == 1);
+        state.set_names(Vec::new());
+        assert!(state.names().is_empty());
+
+        state.lock_queue().push(1);
+        state.set_queue(vec![1, 2]);
+        assert!(*state.lock_queue() == vec![1, 2]);
+
+        state.write_config().push_str("debug");
+        assert!(*state.read_config() == "debug");
+        state.set_config(String::from("release"));
+        assert!(*state.read_config() == "release");
+    }
+
+    mod parking_lot {
+        #[derive(Default)]
+        pub struct Mutex<T>(pub T);
+
+        #[derive(Default)]
+        pub struct Arc<T>(pub T);
+    }
+
+    #[derive(Default, trl)]
+    #[getters]
+    #[setters]
+    struct ForeignState {
+        queue: parking_lot::Mutex<Vec<u32>>,
+        name: Cell<String>,
+        flags: std::cell::Cell<Option<u8>>,
+    }
+
+    #[test]
+    fn interior_fallback_test() {
+        let state = ForeignState::default();
+
+        assert!(state.queue().0.is_empty());
+
+        state.set_name(String::from("John"));
+        assert!(state.name().take() == "John");
+
+        state.set_flags(Some(1));
+        assert!(state.get_flags() == Some(1));
+    }
+
+    #[derive(Default, trl)]
+    #[getters(ordering = Acquire)]
+    #[setters(ordering = AcqRel)]
+    struct Stats {
+        requests: AtomicU64,
+        #[get(ordering = Relaxed, prefix = is_)]
+        running: AtomicBool,
+    }
+
+    #[test]
+    fn atomic_test() {
+        let stats = Stats::default();
+
+        stats.set_requests(1);
+        assert!(stats.fetch_add_requests(2) == 1);
+        assert!(stats.requests() == 3);
+        assert!(stats.swap_requests(5) == 3);
+        assert!(stats.compare_exchange_requests(4, 6) == Err(5));
+        assert!(stats.compare_exchange_requests(5, 6) == Ok(5));
+
+        stats.set_running(true);
+        assert!(stats.is_running() && stats.running());
+    }
+
+    #[derive(trl)]
+    #[getters(shared, excludes = [tags])]
+    struct Document {
+        title: Arc<String>,
+        #[get(shared, prefix = get_)]
+        tags: Rc<Vec<String>>,
+        owner: parking_lot::Arc<u64>,
+    }
+
+    #[test]
+    fn shared_getters_test() {
+        let mut doc = Document {
+            title: Arc::new(String::from("Draft")),
+            tags: Rc::new(Vec::new()),
+            owner: parking_lot::Arc(1),
+        };
+
+        let title = doc.title_arc();
+        doc.title_mut().push('!');
+        assert!(doc.title() == "Draft!" && *title == "Draft");
+
+        doc.get_tags_mut().push(String::from("new"));
+        assert!(doc.get_tags().len() == 1 && Rc::strong_count(&doc.get_tags_rc()) == 2);
+        assert!(doc.owner().0 == 1);
+    }
+
+    #[derive(trl)]
+    struct CountPolls<F> {
+        #[pin]
+        inner: F,
+        polls: u32,
+    }
+
+    impl<F: Future> Future for CountPolls<F> {
+        type Output = (F::Output, u32);
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            let this = self.project();
+            *this.polls += 1;
+
+            match this.inner.poll(cx) {
+                Poll::Ready(output) => Poll::Ready((output, *this.polls)),
+                Poll::Pending => Poll::Pending,
+            }
+        }
+    }
+
+    fn assert_unpin<T: Unpin>(_: &T) {}
+
+    #[test]
+    fn pin_projection_test() {
+        let mut future = CountPolls {
+            inner: std::future::ready(5),
+            polls: 0,
+        };
+        assert_unpin(&future);
+
+        let mut cx = Context::from_waker(Waker::noop());
+        assert!(Pin::new(&mut future).poll(&mut cx) == Poll::Ready((5, 1)));
+
+        let mut future = CountPolls {
+            inner: std::future::ready(7),
+            polls: 0,
+        };
+        assert!(Pin::new(&mut future).inner_pinned().poll(&mut cx) == Poll::Ready(7));
+    }
+
+    #[derive(trl)]
+    struct TakeOutput<F>
+    where
+        F: Future,
+    {
+        #[pin]
+        inner: F,
+        output: Option<F::Output>,
+    }
+
+    impl<F> Future for TakeOutput<F>
+    where
+        F: Future,
+    {
+        type Output = ();
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            let this = self.project();
+            *this.output = Some(std::task::ready!(this.inner.poll(cx)));
+
+            Poll::Ready(())
+        }
+    }
+
+    #[test]
+    fn pin_projection_where_test() {
+        let mut future = TakeOutput {
+            inner: std::future::ready(3),
+            output: None,
+        };
+        assert_unpin(&future);
+
+        let mut cx = Context::from_waker(Waker::noop());
+        assert!(Pin::new(&mut future).poll(&mut cx) == Poll::Ready(()));
+        assert!(future.output == Some(3));
+    }
+
+    #[derive(trl)]
+    #[getters(trait = HasIdentity, excludes = [members])]
+    #[trl_trait]
+    struct Member {
+        id: u64,
+        name: String,
+        members: Vec<u64>,
+    }
+
+    #[derive(trl)]
+    #[getters(trait = HasIdentity)]
+    struct Team {
+        id: u64,
+        name: String,
+    }
+
+    fn describe(value: &impl HasIdentity) -> String {
+        format!("{}: {}", value.id(), value.name())
+    }
+
+    #[test]
+    fn getters_trait_test() {
+        let member = Member {
+            id: 1,
+            name: String::from("John"),
+            members: vec![],
+        };
+        let team = Team {
+            id: 2,
+            name: String::from("Core"),
+        };
+
+        assert!(describe(&member) == "1: John" && describe(&team) == "2: Core");
+        assert!(member.members.is_empty());
+    }
+
+    #[derive(trl)]
+    #[getters(trait = Labeled)]
+    #[trl_trait]
+    struct Label<'a, T>
+    where
+        T: Clone,
+    {
+        text: &'a str,
+        value: T,
+    }
+
+    fn label_value<'a, T: Clone>(label: &impl Labeled<'a, T>) -> T {
+        label.value().clone()
+    }
+
+    #[test]
+    fn getters_trait_generics_test() {
+        let label = Label {
+            text: "answer",
+            value: 42,
+        };
+
+        assert!(*label.text() == "answer" && label_value(&label) == 42);
+    }
+
+    /// Documented struct, doc comments on fields are copied into the generated docs
+    #[derive(trl)]
+    #[getters]
+    #[constructor(doc = "Creates an account")]
+    struct Account {
+        /// Unique id
+        id: u64,
+        #[set(doc = "Renames the account")]
+        name: String,
+    }
+
+    #[test]
+    fn documented_fields_test() {
+        let mut account = Account::new(1, String::from("John"));
+        account.set_name(String::from("Jane"));
+
+        assert!(*account.id() == 1 && account.name() == "Jane");
+    }
+
+    #[derive(trl)]
+    #[getters(attrs(must_use, inline))]
+    #[setters]
+    #[constructor(attrs(must_use))]
+    struct Gated {
+        id: u64,
+        #[cfg(any())]
+        disabled: String,
+        #[deprecated]
+        legacy: u32,
+    }
+
+    #[test]
+    fn propagated_attributes_test() {
+        let mut gated = Gated::new(1, 2);
+        gated.set_id(3);
+
+        #[allow(deprecated)]
+        let legacy = *gated.legacy();
+        assert!(*gated.id() == 3 && legacy == 2);
+    }
+
+    #[derive(trl)]
+    #[getters(const, inline)]
+    #[setters(inline(always))]
+    #[constructor(const)]
+    struct GpioPin {
+        port: u8,
+        #[trl(default = true)]
+        enabled: bool,
+    }
+
+    const LED: GpioPin = GpioPin::new(13);
+    const LED_PORT: u8 = *LED.port();
+
+    #[test]
+    fn const_accessors_test() {
+        let mut pin = LED;
+        pin.set_enabled(false);
+
+        assert!(LED_PORT == 13 && *LED.enabled() && !*pin.enabled());
+    }
+
+    #[derive(trl)]
+    #[getters(both, includes = [name])]
+    #[setters(suffix = _value, includes = [name])]
+    struct Profile {
+        name: String,
+        #[get(both, suffix = _ref, mut_suffix = _edit)]
+        tags: Vec<String>,
+    }
+
+    #[test]
+    fn both_getters_test() {
+        let mut profile = Profile {
+            name: String::from("John"),
+            tags: vec![],
+        };
+
+        profile.name_mut().push('!');
+        profile.tags_edit().push(String::from("admin"));
+        assert!(profile.name() == "John!" && profile.tags_ref().len() == 1);
+
+        profile.set_name_value(String::from("Jane"));
+        assert!(profile.name() == "Jane");
+    }
+
+    #[derive(trl)]
+    #[getters(both, shared, mut_suffix = _edit)]
+    struct Draft {
+        title: String,
+        notes: RefCell<Vec<String>>,
+        tags: Rc<Vec<String>>,
+    }
+
+    #[test]
+    fn mut_suffix_wrappers_test() {
+        let mut draft = Draft {
+            title: String::new(),
+            notes: RefCell::new(Vec::new()),
+            tags: Rc::new(Vec::new()),
+        };
+
+        draft.title_edit().push('a');
+        draft.notes_edit().push(String::from("note"));
+        draft.tags_edit().push(String::from("tag"));
+        assert!(draft.title() == "a" && draft.notes().len() == 1 && draft.tags().len() == 1);
+    }
+
+    #[derive(trl)]
+    #[getters(include = [id])]
+    #[setters(exclude = [id])]
+    struct Wallet {
+        id: u64,
+        balance: i64,
+    }
+
+    #[test]
+    fn include_exclude_aliases_test() {
+        let mut wallet = Wallet { id: 1, balance: 0 };
+        wallet.set_balance(100);
+
+        assert!(*wallet.id() == 1 && wallet.balance == 100);
+    }
+
+    #[derive(trl)]
+    #[getters(includes = ["*_id", "created_?t"])]
+    #[setters(types = [String, Option<_>], excludes = [name])]
+    struct Order {
+        order_id: u64,
+        user_id: u64,
+        created_at: u64,
+        name: String,
+        note: Option<String>,
+    }
+
+    #[test]
+    fn field_patterns_test() {
+        let mut order = Order {
+            order_id: 1,
+            user_id: 2,
+            created_at: 3,
+            name: String::from("Order"),
+            note: None,
+        };
+        order.set_note(Some(String::from("fragile")));
+
+        assert!(*order.order_id() == 1 && *order.user_id() == 2 && *order.created_at() == 3);
+        assert!(order.note.as_deref() == Some("fragile") && order.name == "Order");
+    }
+
+    #[allow(dead_code)]
+    #[derive(trl)]
+    #[eq(types = [u64])]
+    #[debug(excludes = ["*_at"], exclude_types = [Vec<_>])]
+    #[parts(includes = ["*_id"])]
+    struct Shipment {
+        order_id: u64,
+        user_id: u64,
+        shipped_at: u64,
+        tags: Vec<String>,
+    }
+
+    #[test]
+    fn shared_field_patterns_test() {
+        let shipment = Shipment {
+            order_id: 1,
+            user_id: 2,
+            shipped_at: 3,
+            tags: vec![String::from("fragile")],
+        };
+        let other = Shipment {
+            order_id: 1,
+            user_id: 2,
+            shipped_at: 3,
+            tags: Vec::new(),
+        };
+
+        assert!(shipment == other);
+        assert!(format!("{:?}", shipment) == "Shipment { order_id: 1, user_id: 2, .. }");
+        assert!(shipment.into_parts() == (1, 2));
+    }
+
+    #[derive(trl)]
+    #[getters(groups = [audit])]
+    #[setters(exclude_groups = [audit])]
+    #[eq(exclude_groups = [audit])]
+    #[debug(exclude_groups = [audit])]
+    struct Invoice {
+        id: u64,
+        #[trl(group = audit)]
+        created_at: u64,
+        #[trl(group = audit)]
+        updated_by: String,
+    }
+
+    #[test]
+    fn field_groups_test() {
+        let mut invoice = Invoice {
+            id: 1,
+            created_at: 10,
+            updated_by: String::from("admin"),
+        };
+        invoice.set_id(2);
+
+        let other = Invoice {
+            id: 2,
+            created_at: 20,
+            updated_by: String::from("system"),
+        };
+
+        assert!(*invoice.created_at() == 10 && invoice.updated_by() == "admin");
+        assert!(invoice == other);
+        assert!(format!("{:?}", invoice) == "Invoice { id: 2, .. }");
+    }
+
+    #[derive(trl)]
+    #[partial(excludes = [meta, owner])]
+    struct PartialGeneric<'a, T, M: Default>
+    where
+        M: Clone,
+    {
+        id: u64,
+        label: &'a str,
+        meta: M,
+        pub owner: T,
+    }
+
+    #[test]
+    fn partial_generics_test() {
+        let mut item = PartialGeneric {
+            id: 1,
+            label: "old",
+            meta: 0u8,
+            owner: String::from("Ann"),
+        };
+
+        let patch: PartialGenericPatch<'_> = PartialGenericPatch {
+            id: None,
+            label: Some("new"),
+        };
+        item.apply_patch(patch);
+
+        assert!(item.label == "new" && item.id == 1 && item.meta == 0 && item.owner == "Ann");
+    }
+}
+
+/// Items generated by every attribute, so `missing_docs` checks their docs
+#[cfg(test)]
+#[deny(missing_docs)]
+pub mod docs_tests {
+    use crate::prelude::*;
+    use std::cell::{Cell, RefCell};
+    use std::sync::atomic::AtomicU64;
+    use std::sync::{Arc, Mutex, RwLock};
+
+    /// A documented user
+    #[derive(Clone, trl)]
+    #[getters]
+    #[setters(track)]
+    #[constructor]
+    #[value]
+    #[partial]
+    #[parts]
+    #[eq]
+    #[hash]
+    #[ord]
+    #[debug]
+    #[display("{name}")]
+    #[trl(default)]
+    pub struct DocumentedUser {
+        /// Identifier
+        id: u64,
+        /// Name
+        name: String,
+        /// Scores
+        #[delegate(len, is_empty, push(&mut self, score: u32))]
+        scores: Vec<u32>,
+        /// Dirty flags
+        #[dirty]
+        dirty: u8,
+    }
+
+    /// A documented future
+    #[derive(trl)]
+    pub struct DocumentedFuture<F> {
+        /// Inner future
+        #[pin]
+        inner: F,
+        /// Poll count
+        polls: u32,
+    }
+
+    /// A documented wrapper
+    #[derive(trl)]
+    #[getters(shared, both, trait = DocumentedGetters)]
+    #[trl_trait]
+    #[setters(validate = check, notify = on_change)]
+    pub struct DocumentedState {
+        /// Hits
+        hits: Cell<u32>,
+        /// Names
+        names: RefCell<Vec<String>>,
+        /// Queue
+        queue: Mutex<Vec<u32>>,
+        /// Config
+        config: RwLock<String>,
+        /// Requests
+        requests: AtomicU64,
+        /// Title
+        #[deref]
+        title: Arc<String>,
+    }
+
+    impl DocumentedState {
+        fn check<T>(&self, _: &str, _: &T) -> Result<(), String> {
+            Ok(())
+        }
+
+        fn on_change<T>(&self, _: &str, _: &T, _: &T) {}
+    }
+
+    #[test]
+    fn missing_docs_test() {
+        let user = DocumentedUser::new(1, String::from("John"), vec![], 0);
+        assert!(user.to_string() == "John" && user.is_empty());
+    }
 }
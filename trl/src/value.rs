@@ -0,0 +1,355 @@
+//! # value
+//!
+//! This module contains the dynamic `Value` type and the `ToValue`/`FromValue` traits
+//! used by the `#[value]` attribute to convert structs to and from maps
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Dynamically typed value
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// Absence of a value, e.g. `None`
+    Null,
+    /// Boolean value
+    Bool(bool),
+    /// Signed integer
+    Int(i64),
+    /// Unsigned integer
+    UInt(u64),
+    /// Floating point number
+    Float(f64),
+    /// String value
+    String(String),
+    /// List of values
+    List(Vec<Value>),
+    /// Map of named values
+    Map(BTreeMap<String, Value>),
+}
+
+impl Value {
+    /// Returns the name of the value type, used in error messages
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            Value::Null => "null",
+            Value::Bool(_) => "bool",
+            Value::Int(_) => "int",
+            Value::UInt(_) => "uint",
+            Value::Float(_) => "float",
+            Value::String(_) => "string",
+            Value::List(_) => "list",
+            Value::Map(_) => "map",
+        }
+    }
+}
+
+/// Field whose value has a wrong type
+#[derive(Debug, Clone, PartialEq)]
+pub struct MistypedField {
+    /// Path to the field, nested fields are separated by `.`
+    pub field: String,
+    /// Expected type
+    pub expected: &'static str,
+    /// Actual type of the value
+    pub found: &'static str,
+}
+
+/// Error returned when a value could not be converted
+///
+/// All problems are collected, so a single error reports every missing, extra and mistyped field
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FromValueError {
+    /// Fields that are absent from the map
+    pub missing: Vec<String>,
+    /// Keys of the map that don't match any field
+    pub extra: Vec<String>,
+    /// Fields whose value has a wrong type
+    pub mistyped: Vec<MistypedField>,
+}
+
+impl FromValueError {
+    /// Creates an error for a value of a wrong type
+    pub fn mistyped(expected: &'static str, found: &Value) -> Self {
+        FromValueError {
+            mistyped: vec![MistypedField {
+                field: String::new(),
+                expected,
+                found: found.type_name(),
+            }],
+            ..Default::default()
+        }
+    }
+
+    /// Returns `true` if there are no errors
+    pub fn is_empty(&self) -> bool {
+        self.missing.is_empty() && self.extra.is_empty() && self.mistyped.is_empty()
+    }
+
+    /// Merges the errors of a nested value, prefixing their paths with `field`
+    pub fn merge_nested(&mut self, field: &str, nested: FromValueError) {
+        let join = |path: String| {
+            if path.is_empty() {
+                field.to_string()
+            } else {
+                format!("{}.{}", field, path)
+            }
+        };
+
+        self.missing.extend(nested.missing.into_iter().map(join));
+        self.extra.extend(nested.extra.into_iter().map(join));
+        self.mistyped
+            .extend(nested.mistyped.into_iter().map(|m| MistypedField {
+                field: join(m.field),
+                ..m
+            }));
+    }
+}
+
+impl fmt::Display for FromValueError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut problems = Vec::new();
+
+        if !self.missing.is_empty() {
+            problems.push(format!("missing fields: {}", self.missing.join(", ")));
+        }
+
+        if !self.extra.is_empty() {
+            problems.push(format!("extra fields: {}", self.extra.join(", ")));
+        }
+
+        for m in &self.mistyped {
+            if m.field.is_empty() {
+                problems.push(format!("expected {}, found {}", m.expected, m.found));
+            } else {
+                problems.push(format!(
+                    "field `{}`: expected {}, found {}",
+                    m.field, m.expected, m.found
+                ));
+            }
+        }
+
+        write!(f, "{}", problems.join("; "))
+    }
+}
+
+impl std::error::Error for FromValueError {}
+
+/// Converts a type into a `Value`
+pub trait ToValue {
+    fn to_value(&self) -> Value;
+}
+
+/// Creates a type from a `Value`
+pub trait FromValue: Sized {
+    fn from_value(value: &Value) -> Result<Self, FromValueError>;
+}
+
+impl ToValue for Value {
+    fn to_value(&self) -> Value {
+        self.clone()
+    }
+}
+
+impl FromValue for Value {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        Ok(value.clone())
+    }
+}
+
+impl ToValue for bool {
+    fn to_value(&self) -> Value {
+        Value::Bool(*self)
+    }
+}
+
+impl FromValue for bool {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        match value {
+            Value::Bool(b) => Ok(*b),
+            _ => Err(FromValueError::mistyped("bool", value)),
+        }
+    }
+}
+
+macro_rules! impl_signed {
+    ($($ty:ty),*) => {$(
+        impl ToValue for $ty {
+            fn to_value(&self) -> Value {
+                Value::Int(*self as i64)
+            }
+        }
+
+        impl FromValue for $ty {
+            fn from_value(value: &Value) -> Result<Self, FromValueError> {
+                let converted = match value {
+                    Value::Int(i) => <$ty>::try_from(*i).ok(),
+                    Value::UInt(u) => <$ty>::try_from(*u).ok(),
+                    _ => None,
+                };
+
+                converted.ok_or_else(|| FromValueError::mistyped(stringify!($ty), value))
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_unsigned {
+    ($($ty:ty),*) => {$(
+        impl ToValue for $ty {
+            fn to_value(&self) -> Value {
+                Value::UInt(*self as u64)
+            }
+        }
+
+        impl FromValue for $ty {
+            fn from_value(value: &Value) -> Result<Self, FromValueError> {
+                let converted = match value {
+                    Value::Int(i) => <$ty>::try_from(*i).ok(),
+                    Value::UInt(u) => <$ty>::try_from(*u).ok(),
+                    _ => None,
+                };
+
+                converted.ok_or_else(|| FromValueError::mistyped(stringify!($ty), value))
+            }
+        }
+    )*};
+}
+
+impl_signed!(i8, i16, i32, i64, isize);
+impl_unsigned!(u8, u16, u32, u64, usize);
+
+impl ToValue for f32 {
+    fn to_value(&self) -> Value {
+        Value::Float(*self as f64)
+    }
+}
+
+impl FromValue for f32 {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        f64::from_value(value)
+            .map(|f| f as f32)
+            .map_err(|_| FromValueError::mistyped("f32", value))
+    }
+}
+
+impl ToValue for f64 {
+    fn to_value(&self) -> Value {
+        Value::Float(*self)
+    }
+}
+
+impl FromValue for f64 {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        match value {
+            Value::Float(f) => Ok(*f),
+            Value::Int(i) => Ok(*i as f64),
+            Value::UInt(u) => Ok(*u as f64),
+            _ => Err(FromValueError::mistyped("f64", value)),
+        }
+    }
+}
+
+impl ToValue for String {
+    fn to_value(&self) -> Value {
+        Value::String(self.clone())
+    }
+}
+
+impl ToValue for str {
+    fn to_value(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+impl FromValue for String {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        match value {
+            Value::String(s) => Ok(s.clone()),
+            _ => Err(FromValueError::mistyped("string", value)),
+        }
+    }
+}
+
+impl<T: ToValue> ToValue for Option<T> {
+    fn to_value(&self) -> Value {
+        match self {
+            Some(v) => v.to_value(),
+            None => Value::Null,
+        }
+    }
+}
+
+impl<T: FromValue> FromValue for Option<T> {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        match value {
+            Value::Null => Ok(None),
+            _ => T::from_value(value).map(Some),
+        }
+    }
+}
+
+impl<T: ToValue> ToValue for Vec<T> {
+    fn to_value(&self) -> Value {
+        Value::List(self.iter().map(ToValue::to_value).collect())
+    }
+}
+
+impl<T: FromValue> FromValue for Vec<T> {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        let Value::List(list) = value else {
+            return Err(FromValueError::mistyped("list", value));
+        };
+
+        let mut result = Vec::with_capacity(list.len());
+        let mut error = FromValueError::default();
+
+        for (i, item) in list.iter().enumerate() {
+            match T::from_value(item) {
+                Ok(v) => result.push(v),
+                Err(e) => error.merge_nested(&i.to_string(), e),
+            }
+        }
+
+        if error.is_empty() {
+            Ok(result)
+        } else {
+            Err(error)
+        }
+    }
+}
+
+impl<T: ToValue> ToValue for BTreeMap<String, T> {
+    fn to_value(&self) -> Value {
+        Value::Map(
+            self.iter()
+                .map(|(k, v)| (k.clone(), v.to_value()))
+                .collect(),
+        )
+    }
+}
+
+impl<T: FromValue> FromValue for BTreeMap<String, T> {
+    fn from_value(value: &Value) -> Result<Self, FromValueError> {
+        let Value::Map(map) = value else {
+            return Err(FromValueError::mistyped("map", value));
+        };
+
+        let mut result = BTreeMap::new();
+        let mut error = FromValueError::default();
+
+        for (k, item) in map {
+            match T::from_value(item) {
+                Ok(v) => {
+                    result.insert(k.clone(), v);
+                }
+                Err(e) => error.merge_nested(k, e),
+            }
+        }
+
+        if error.is_empty() {
+            Ok(result)
+        } else {
+            Err(error)
+        }
+    }
+}
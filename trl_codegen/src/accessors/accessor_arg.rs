@@ -2,82 +2,265 @@
 //! This module contains the `AccessorArg` enum which represents a single argument of an attribute
 //!
 
+use crate::helpers::{check_arg_name, unknown_arg_error};
 use crate::modifier::Modifier;
+use crate::ordering::AtomicOrdering;
+use crate::selection::SelectionArg;
+use crate::track::Track;
+use syn::ext::IdentExt;
 use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
-use syn::{Error, Expr, ExprArray, MetaNameValue, Result, Token};
+use syn::token::Paren;
+use syn::{
+    Error, Expr, ExprLit, Ident, Lit, Meta, MetaNameValue, Path, Result, Token, Type, TypePath,
+    parenthesized,
+};
+
+/// Names of all accessor arguments
+const ARG_NAMES: &[&str] = &[
+    "includes",
+    "excludes",
+    "types",
+    "exclude_types",
+    "groups",
+    "exclude_groups",
+    "prefix",
+    "suffix",
+    "mut_suffix",
+    "both",
+    "pub",
+    "ref",
+    "mut ref",
+    "move",
+    "name",
+    "track",
+    "notify",
+    "validate",
+    "map",
+    "error",
+    "ordering",
+    "shared",
+    "trait",
+    "doc",
+    "attrs",
+    "inline",
+    "const",
+];
+
+/// Names of `#[getters]` arguments
+pub const GETTERS_ARG_NAMES: &[&str] = &[
+    "includes",
+    "excludes",
+    "types",
+    "exclude_types",
+    "groups",
+    "exclude_groups",
+    "prefix",
+    "suffix",
+    "mut_suffix",
+    "both",
+    "pub",
+    "ref",
+    "mut ref",
+    "move",
+    "ordering",
+    "shared",
+    "trait",
+    "attrs",
+    "inline",
+    "const",
+];
+
+/// Names of `#[setters]` arguments
+pub const SETTERS_ARG_NAMES: &[&str] = &[
+    "includes",
+    "excludes",
+    "types",
+    "exclude_types",
+    "groups",
+    "exclude_groups",
+    "prefix",
+    "suffix",
+    "pub",
+    "track",
+    "notify",
+    "validate",
+    "error",
+    "ordering",
+    "attrs",
+    "inline",
+    "const",
+];
+
+/// Names of `#[get]` arguments
+pub const GET_ARG_NAMES: &[&str] = &[
+    "prefix",
+    "suffix",
+    "mut_suffix",
+    "both",
+    "ref",
+    "mut ref",
+    "move",
+    "name",
+    "ordering",
+    "shared",
+    "doc",
+    "attrs",
+    "inline",
+    "const",
+];
+
+/// Names of `#[set]` arguments
+pub const SET_ARG_NAMES: &[&str] = &[
+    "prefix", "suffix", "name", "notify", "validate", "map", "error", "ordering", "doc", "attrs",
+    "inline", "const",
+];
+
+/// Field-level args that `#[getters]`/`#[setters]` don't take, with the reason shown by the error
+const FIELD_ONLY_ARGS: &[(&str, &str)] = &[
+    (
+        "map",
+        "`map` converts the value of a single field, use `#[set(map = ...)]` on the field",
+    ),
+    (
+        "doc",
+        "`doc` replaces the docs of a single method, use `#[get(doc = ...)]` or `#[set(doc = ...)]` on the field",
+    ),
+];
 
 /// Enum AccessorArg represents a single argument of an accessor attribute
 pub enum AccessorArg {
-    /// Fields to include
-    Includes(Vec<String>),
-    /// Fields to exclude
-    Excludes(Vec<String>),
+    /// Fields to include or exclude, e.g. `includes = [...]` or `types = [...]`
+    Selection(SelectionArg),
     /// Prefix
     Prefix(String),
+    /// Suffix
+    Suffix(String),
+    /// Suffix of the `&mut self` getter generated by `both`
+    MutSuffix(String),
+    /// Generate a `&mut self` getter besides the `&self` one
+    Both,
     /// Include public fields
     Pub,
     /// `self` modifier
     Modifier(Modifier),
     /// method (getter/setter) name
     Name(String),
+    /// Change tracking mode of setters
+    Track(Track),
+    /// Hook called by setters after the assignment
+    Notify(Path),
+    /// Validator called by setters before the assignment
+    Validate(Path),
+    /// Function that transforms the value before it is stored
+    Map(Path),
+    /// Error type of the validator
+    Error(Type),
+    /// Memory ordering of atomic accessors
+    Ordering(AtomicOrdering),
+    /// Getters of `Rc`/`Arc` fields return the pointed value
+    Shared,
+    /// Trait implemented by the generated methods
+    Trait(Path),
+    /// Docs of the generated method
+    Doc(String),
+    /// Attributes added to the generated methods, e.g. `attrs(inline, must_use)`
+    Attrs(Vec<Meta>),
+    /// `inline` or `inline(...)` attribute added to the generated methods
+    Inline(Meta),
+    /// Generate `const fn`
+    Const,
 }
 
 impl AccessorArg {
     /// If the parsed value is MetaNameValue, this means its the construction like: name = value.
     /// This method parses this construction into an `Arg`
     pub fn nv_to_arg(nv: &MetaNameValue) -> Result<Self> {
-        match nv
+        let ident = nv
             .path
             .get_ident()
-            .expect("Wrong name. Expected identifier")
-            .to_string()
-            .as_str()
-        {
-            "includes" => Ok(AccessorArg::Includes(AccessorArg::brackets_to_vec(
+            .ok_or_else(|| Error::new(nv.path.span(), "Wrong name. Expected identifier"))?;
+
+        match ident.to_string().as_str() {
+            "prefix" => Ok(AccessorArg::Prefix(AccessorArg::ident_to_string(
                 &nv.value,
             )?)),
-            "excludes" => Ok(AccessorArg::Excludes(AccessorArg::brackets_to_vec(
+            "suffix" => Ok(AccessorArg::Suffix(AccessorArg::ident_to_string(
                 &nv.value,
             )?)),
-            "prefix" => Ok(AccessorArg::Prefix(AccessorArg::ident_to_string(
+            "mut_suffix" => Ok(AccessorArg::MutSuffix(AccessorArg::ident_to_string(
                 &nv.value,
             )?)),
             "name" => Ok(AccessorArg::Name(AccessorArg::ident_to_string(&nv.value)?)),
-            _ => Result::Err(Error::new(nv.span(), "Unknown arg name")),
+            "notify" => Ok(AccessorArg::Notify(AccessorArg::expr_to_path(&nv.value)?)),
+            "validate" => Ok(AccessorArg::Validate(AccessorArg::expr_to_path(&nv.value)?)),
+            "map" => Ok(AccessorArg::Map(AccessorArg::expr_to_path(&nv.value)?)),
+            "error" => Ok(AccessorArg::Error(Type::Path(TypePath {
+                qself: None,
+                path: AccessorArg::expr_to_path(&nv.value)?,
+            }))),
+            "doc" => Ok(AccessorArg::Doc(AccessorArg::lit_to_string(&nv.value)?)),
+            "ordering" => Ok(AccessorArg::Ordering(AtomicOrdering::from_expr(&nv.value)?)),
+            _ => Err(unknown_arg_error(ident, ARG_NAMES)),
         }
     }
 
-    /// This method parses the `[...]` brackets expression into a vector
-    pub fn brackets_to_vec(brackets: &Expr) -> Result<Vec<String>> {
-        if let Expr::Array(ExprArray { elems, .. }) = brackets {
-            let res = elems
+    /// Parses an argument of the `attribute`, which takes only the `names` arguments.
+    /// Other arguments are reported before parsing, so the error lists only the `names`
+    pub fn parse_in(input: ParseStream, attribute: &str, names: &[&str]) -> Result<Self> {
+        if let Ok(ident) = input.fork().call(Ident::parse_any) {
+            let field_only = FIELD_ONLY_ARGS
                 .iter()
-                .map(|e| {
-                    if let Expr::Path(path) = e {
-                        path.path
-                            .get_ident()
-                            .expect("Could not parse value")
-                            .to_string()
-                    } else {
-                        "".to_string()
-                    }
-                })
-                .collect::<Vec<_>>();
-
-            return Result::Ok(res);
-        };
+                .find(|(arg, _)| ident == *arg && !names.contains(arg));
+
+            if let Some((name, reason)) = field_only {
+                return Err(Error::new(
+                    ident.span(),
+                    format!("`{name}` is not an argument of `#[{attribute}]`. {reason}"),
+                ));
+            }
+        }
+
+        check_arg_name(input, attribute, names, ARG_NAMES)?;
+
+        input.parse()
+    }
+
+    /// This method parses the path, e.g. `path::to::callback`
+    pub fn expr_to_path(expr: &Expr) -> Result<Path> {
+        if let Expr::Path(path) = expr {
+            Ok(path.path.clone())
+        } else {
+            Err(Error::new(
+                expr.span(),
+                "Could not parse arg value. Expected path",
+            ))
+        }
+    }
 
-        Err(Error::new(
-            brackets.span(),
-            "Wrong expressing. Expected array",
-        ))
+    /// This method parses the string literal, e.g. `"text"`
+    pub fn lit_to_string(expr: &Expr) -> Result<String> {
+        if let Expr::Lit(ExprLit {
+            lit: Lit::Str(s), ..
+        }) = expr
+        {
+            Ok(s.value())
+        } else {
+            Err(Error::new(
+                expr.span(),
+                "Could not parse arg value. Expected string",
+            ))
+        }
     }
 
     /// This method parses the single identifier into string
     pub fn ident_to_string(expr: &Expr) -> Result<String> {
         if let Expr::Path(path) = expr {
-            Ok(path.path.get_ident().expect("Expected ident").to_string())
+            path.path
+                .get_ident()
+                .map(|ident| ident.to_string())
+                .ok_or_else(|| Error::new(expr.span(), "Could not parse arg value. Expected ident"))
         } else {
             Err(Error::new(
                 expr.span(),
@@ -89,11 +272,55 @@ impl AccessorArg {
 
 impl Parse for AccessorArg {
     fn parse(input: ParseStream) -> Result<Self> {
+        if SelectionArg::peek(input) {
+            return Ok(AccessorArg::Selection(input.parse()?));
+        }
+
         let arg = if let Ok(modifier) = input.parse::<Modifier>() {
             AccessorArg::Modifier(modifier)
+        } else if input.peek(Ident)
+            && input.peek2(Paren)
+            && input.fork().parse::<Ident>()? == "attrs"
+        {
+            input.parse::<Ident>()?;
+            let content;
+            parenthesized!(content in input);
+            AccessorArg::Attrs(
+                Punctuated::<Meta, Token![,]>::parse_terminated(&content)?
+                    .into_iter()
+                    .collect(),
+            )
+        } else if input.peek(Ident)
+            && !input.peek2(Token![=])
+            && input.fork().parse::<Ident>()? == "both"
+        {
+            input.parse::<Ident>()?;
+            AccessorArg::Both
+        } else if input.peek(Token![const]) {
+            input.parse::<Token![const]>()?;
+            AccessorArg::Const
+        } else if input.peek(Ident) && input.fork().parse::<Ident>()? == "inline" {
+            AccessorArg::Inline(input.parse::<Meta>()?)
+        } else if input.peek(Token![trait]) {
+            input.parse::<Token![trait]>()?;
+            input.parse::<Token![=]>()?;
+            AccessorArg::Trait(input.parse::<Path>()?)
         } else if input.peek(Token![pub]) {
             input.parse::<Token![pub]>()?;
             AccessorArg::Pub
+        } else if input.peek(Ident)
+            && !input.peek2(Token![=])
+            && input.fork().parse::<Ident>()? == "shared"
+        {
+            input.parse::<Ident>()?;
+            AccessorArg::Shared
+        } else if input.peek(Ident)
+            && !input.peek2(Token![=])
+            && input.fork().parse::<Ident>()? == "track"
+        {
+            AccessorArg::Track(input.parse::<Track>()?)
+        } else if input.peek(Ident) && !input.peek2(Token![=]) {
+            return Err(unknown_arg_error(&input.parse::<Ident>()?, ARG_NAMES));
         } else if let Ok(nv) = input.parse::<MetaNameValue>() {
             AccessorArg::nv_to_arg(&nv)?
         } else {
@@ -5,25 +5,56 @@
 
 use syn::{Token, punctuated::Punctuated};
 
-use crate::{accessors::AccessorArg, modifier::Modifier, new_from_args::NewFromArgs};
+use crate::{
+    accessors::{AccessorArg, SetterHooks},
+    hook::Hook,
+    method_attrs::MethodAttrs,
+    modifier::Modifier,
+    new_from_args::NewFromArgs,
+    ordering::AtomicOrdering,
+};
 
 /// Information about field for which a method is going to be generated
 #[derive(Debug)]
 pub struct AccessorFieldAttrs {
     /// Method prefix
     pub prefix: String,
+    /// Method suffix
+    pub suffix: String,
+    /// Whether to generate a `&mut self` getter besides the `&self` one
+    pub both: bool,
+    /// Suffix of the `&mut self` getter. The default value is `_mut`
+    pub mut_suffix: String,
     /// Method name
     pub name: String,
     /// Method `self` modifier
     pub modifier: Modifier,
+    /// Setter statements besides the assignment
+    pub hooks: SetterHooks,
+    /// Memory ordering of atomic accessors
+    pub ordering: AtomicOrdering,
+    /// Whether the getter of an `Rc`/`Arc` field returns the pointed value
+    pub shared: bool,
+    /// Docs and attributes of the generated method
+    pub method: MethodAttrs,
+    /// Whether to generate `const fn`
+    pub is_const: bool,
 }
 
 impl AccessorFieldAttrs {
     pub fn from_values(prefix: String, name: String, modifier: Modifier) -> AccessorFieldAttrs {
         AccessorFieldAttrs {
             prefix,
+            suffix: String::new(),
+            both: false,
+            mut_suffix: String::from("_mut"),
             name,
             modifier,
+            hooks: SetterHooks::default(),
+            ordering: AtomicOrdering::default(),
+            shared: false,
+            method: MethodAttrs::default(),
+            is_const: false,
         }
     }
 }
@@ -31,22 +62,55 @@ impl AccessorFieldAttrs {
 impl NewFromArgs<AccessorArg> for AccessorFieldAttrs {
     fn new(args: Punctuated<AccessorArg, Token![,]>) -> AccessorFieldAttrs {
         let mut prefix = String::new();
+        let mut suffix = String::new();
+        let mut both = false;
+        let mut mut_suffix = String::from("_mut");
         let mut name = String::new();
         let mut modifier = Modifier::Ref;
+        let mut hooks = SetterHooks::default();
+        let mut ordering = AtomicOrdering::default();
+        let mut shared = false;
+        let mut method = MethodAttrs::default();
+        let mut is_const = false;
 
         for arg in args {
             match arg {
                 AccessorArg::Prefix(p) => prefix = p,
+                AccessorArg::Suffix(s) => suffix = s,
+                AccessorArg::Both => both = true,
+                AccessorArg::MutSuffix(s) => mut_suffix = s,
                 AccessorArg::Name(n) => name = n,
                 AccessorArg::Modifier(m) => modifier = m,
-                _ => {}
+                AccessorArg::Notify(n) => hooks.notify = Some(Hook::Function(n)),
+                AccessorArg::Validate(v) => hooks.validate = Some(Hook::Function(v)),
+                AccessorArg::Map(m) => hooks.map = Some(m),
+                AccessorArg::Error(e) => hooks.error = Some(e),
+                AccessorArg::Ordering(o) => ordering = o,
+                AccessorArg::Shared => shared = true,
+                AccessorArg::Doc(d) => method.doc = Some(d),
+                AccessorArg::Attrs(a) => method.attrs.extend(a),
+                AccessorArg::Inline(i) => method.attrs.push(i),
+                AccessorArg::Const => is_const = true,
+                // Struct-level args, rejected by `AccessorArg::parse_in`
+                AccessorArg::Selection(_)
+                | AccessorArg::Pub
+                | AccessorArg::Track(_)
+                | AccessorArg::Trait(_) => {}
             }
         }
 
         AccessorFieldAttrs {
             prefix,
+            suffix,
+            both,
+            mut_suffix,
             name,
             modifier,
+            hooks,
+            ordering,
+            shared,
+            method,
+            is_const,
         }
     }
 }
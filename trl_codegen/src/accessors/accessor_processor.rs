@@ -2,23 +2,30 @@
 //! This module contains functions that generate method from the provided information
 
 use proc_macro2::TokenStream;
-use quote::format_ident;
-use syn::{Field, ItemStruct};
+use quote::{format_ident, quote};
+use syn::{Error, Field, ImplItem, ItemImpl, ItemStruct, Visibility, parse2};
 
 use crate::{
     accessors::AccessorFieldAttrs,
     accessors::AccessorStructAttrs,
-    helpers::{fill_includes_if_empty, generate_getter, generate_setter, should_field_be_added},
+    helpers::{
+        fill_includes_if_empty, generate_getter, generate_setter, has_attribute,
+        should_field_be_added, to_pascal_case,
+    },
+    interior::Interior,
+    modifier::Modifier,
+    shared::SharedPointer,
+    track::Track,
 };
 
 /// Generates `getters` `TokenStream` based on the provided `AccessorStructAttrs`
 pub fn process_getters(mut attrs: AccessorStructAttrs, input: &ItemStruct) -> TokenStream {
-    fill_includes_if_empty(&mut attrs.includes, &input.fields);
+    fill_includes_if_empty(&mut attrs.selection.includes, &input.fields);
 
     let mut result = TokenStream::new();
 
     input.fields.iter().for_each(|field| {
-        if !should_field_be_added(&attrs, &field) {
+        if !should_field_be_added(&attrs, field) {
             return;
         }
 
@@ -28,27 +35,269 @@ pub fn process_getters(mut attrs: AccessorStructAttrs, input: &ItemStruct) -> To
     result
 }
 
+/// Generates an impl of the `trait` argument with the getters
+///
+/// If `define_trait` is set, the trait itself is also defined with signatures of the getters
+pub fn process_getters_trait(
+    attrs: AccessorStructAttrs,
+    input: &ItemStruct,
+    define_trait: bool,
+) -> TokenStream {
+    let Some(trait_path) = attrs.trait_path.clone() else {
+        return TokenStream::new();
+    };
+
+    if attrs.is_const {
+        return Error::new_spanned(trait_path, "Trait methods can't be `const`").to_compile_error();
+    }
+
+    let getters = process_getters(attrs, input);
+
+    let getters = match parse2::<ItemImpl>(quote! { impl Getters { #getters } }) {
+        Ok(getters) => getters,
+        Err(e) => return e.to_compile_error(),
+    };
+
+    let methods = getters
+        .items
+        .into_iter()
+        .filter_map(|item| match item {
+            ImplItem::Fn(mut method) => {
+                method.vis = Visibility::Inherited;
+                Some(method)
+            }
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    let struct_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    if !define_trait {
+        return quote! {
+            impl #impl_generics #trait_path for #struct_name #ty_generics #where_clause {
+                #(#methods)*
+            }
+        };
+    }
+
+    let Some(trait_name) = trait_path.get_ident() else {
+        return Error::new_spanned(
+            trait_path,
+            "`#[trl_trait]` requires the trait name to be a single identifier",
+        )
+        .to_compile_error();
+    };
+
+    // The trait takes the generics of the struct, since the getter signatures refer to them
+    let vis = &input.vis;
+    let generics = &input.generics;
+    let signatures = methods.iter().map(|method| {
+        let docs = method
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("doc"));
+        let sig = &method.sig;
+
+        quote! { #(#docs)* #sig; }
+    });
+    let doc = format!("Getters of [`{}`]", input.ident);
+
+    quote! {
+        #[doc = #doc]
+        #vis trait #trait_name #generics #where_clause {
+            #(#signatures)*
+        }
+
+        impl #impl_generics #trait_name #ty_generics for #struct_name #ty_generics #where_clause {
+            #(#methods)*
+        }
+    }
+}
+
 /// Generates `setters` `TokenStream` based on the provided `AccessorStructAttrs`
 pub fn process_setters(mut attrs: AccessorStructAttrs, input: &ItemStruct) -> TokenStream {
-    fill_includes_if_empty(&mut attrs.includes, &input.fields);
+    fill_includes_if_empty(&mut attrs.selection.includes, &input.fields);
 
     if attrs.prefix.is_empty() {
         attrs.prefix = String::from("set_");
     }
 
+    let Some(track) = attrs.track.clone() else {
+        let mut result = TokenStream::new();
+
+        input.fields.iter().for_each(|field| {
+            if !should_field_be_added(&attrs, field) || has_attribute(field, "dirty") {
+                return;
+            }
+
+            result.extend(process_set(attrs.clone().into(), field));
+        });
+
+        return result;
+    };
+
+    let Some(dirty) = dirty_field(input) else {
+        return Error::new(
+            input.ident.span(),
+            "`#[setters(track)]` requires an integer field marked with `#[dirty]` to store the dirty flags",
+        )
+        .to_compile_error();
+    };
+
+    let field_enum = field_enum_name(input);
+    let tracked = tracked_fields(&attrs, input);
+
     let mut result = TokenStream::new();
+    let mut dirty_checks = TokenStream::new();
 
-    input.fields.iter().for_each(|field| {
-        if !should_field_be_added(&attrs, &field) {
-            return;
+    tracked.iter().enumerate().for_each(|(bit, field)| {
+        let fin = field.ident.clone().unwrap();
+        let is_dirty_name = format_ident!("is_{}_dirty", fin);
+        let doc = format!(
+            "Returns `true` if the `{}` was set since the last `clear_dirty`",
+            fin
+        );
+
+        let mark = quote! { self.#dirty |= 1 << #bit; };
+        let mark = match track {
+            Track::Always => mark,
+            Track::Eq => quote! {
+                if self.#fin != value {
+                    #mark
+                }
+            },
+        };
+
+        let mut field_attrs: AccessorFieldAttrs = attrs.clone().into();
+        field_attrs.hooks.mark_dirty = mark;
+        result.extend(process_set(field_attrs, field));
+
+        dirty_checks.extend(quote! {
+            #[doc = #doc]
+            pub fn #is_dirty_name(&self) -> bool {
+                self.#dirty & (1 << #bit) != 0
+            }
+        });
+    });
+
+    let variants = tracked
+        .iter()
+        .map(|field| to_pascal_case(field.ident.as_ref().unwrap()));
+
+    result.extend(quote! {
+        /// Returns `true` if any tracked field was set since the last `clear_dirty`
+        pub fn is_dirty(&self) -> bool {
+            self.#dirty != 0
+        }
+
+        /// Returns the tracked fields set since the last `clear_dirty`
+        pub fn dirty_fields(&self) -> impl ::std::iter::Iterator<Item = #field_enum> {
+            let dirty = self.#dirty;
+
+            [#(#field_enum::#variants),*]
+                .into_iter()
+                .enumerate()
+                .filter(move |(bit, _)| dirty & (1 << bit) != 0)
+                .map(|(_, field)| field)
+        }
+
+        /// Marks all tracked fields as clean
+        pub fn clear_dirty(&mut self) {
+            self.#dirty = 0;
         }
 
-        result.extend(process_set(attrs.clone().into(), field));
+        #dirty_checks
     });
 
     result
 }
 
+/// Generates the `{Struct}Field` enum with a variant for each field tracked by `setters(track)`
+pub fn process_setters_field_enum(
+    mut attrs: AccessorStructAttrs,
+    input: &ItemStruct,
+) -> TokenStream {
+    fill_includes_if_empty(&mut attrs.selection.includes, &input.fields);
+
+    let vis = &input.vis;
+    let field_enum = field_enum_name(input);
+
+    let tracked = tracked_fields(&attrs, input);
+
+    // Every tracked field needs its own bit of the `#[dirty]` integer
+    let bits_check = input
+        .fields
+        .iter()
+        .find(|field| has_attribute(field, "dirty"))
+        .map(|dirty| {
+            let ty = &dirty.ty;
+            let count = tracked.len();
+            let message = format!(
+                "`#[dirty]` field `{}` has fewer bits than the {} fields tracked by `#[setters(track)]`",
+                dirty.ident.as_ref().unwrap(),
+                count
+            );
+
+            quote! {
+                const _: () = ::std::assert!(#count <= <#ty>::BITS as usize, #message);
+            }
+        });
+
+    let (variants, names): (Vec<_>, Vec<_>) = tracked
+        .iter()
+        .map(|field| {
+            let fin = field.ident.as_ref().unwrap();
+            (to_pascal_case(fin), fin.to_string())
+        })
+        .unzip();
+
+    let doc = format!("Fields of [`{}`] tracked by `dirty_fields`", input.ident);
+    let variant_docs = names.iter().map(|name| format!("The `{}` field", name));
+
+    quote! {
+        #[doc = #doc]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #vis enum #field_enum {
+            #(#[doc = #variant_docs] #variants),*
+        }
+
+        impl #field_enum {
+            /// Returns the field name
+            pub fn as_str(&self) -> &'static str {
+                match *self {
+                    #(#field_enum::#variants => #names),*
+                }
+            }
+        }
+
+        #bits_check
+    }
+}
+
+/// Returns the name of the field marked with `#[dirty]`
+fn dirty_field(input: &ItemStruct) -> Option<syn::Ident> {
+    input
+        .fields
+        .iter()
+        .find(|field| has_attribute(field, "dirty"))
+        .and_then(|field| field.ident.clone())
+}
+
+/// Returns the `{Struct}Field` enum name
+fn field_enum_name(input: &ItemStruct) -> syn::Ident {
+    format_ident!("{}Field", input.ident)
+}
+
+/// Returns fields whose setters track changes
+fn tracked_fields<'a>(attrs: &AccessorStructAttrs, input: &'a ItemStruct) -> Vec<&'a Field> {
+    input
+        .fields
+        .iter()
+        .filter(|field| should_field_be_added(attrs, field) && !has_attribute(field, "dirty"))
+        .collect()
+}
+
 /// Generates `get` `TokenStream` based on the provided `AccessorFieldAttrs`
 pub fn process_get(attrs: AccessorFieldAttrs, field: &Field) -> TokenStream {
     let fin = &field.ident.clone().unwrap();
@@ -60,13 +309,76 @@ pub fn process_get(attrs: AccessorFieldAttrs, field: &Field) -> TokenStream {
         getter_name = format_ident!("{}", attrs.name);
     }
 
-    if !attrs.prefix.is_empty() {
-        getter_name = format_ident!("{}{}", attrs.prefix, getter_name);
+    let name = getter_name.clone();
+    getter_name = format_ident!("{}{}", getter_name, attrs.suffix);
+
+    let shared = attrs.shared.then(|| SharedPointer::from_type(ty)).flatten();
+    let interior = Interior::from_type(ty, attrs.ordering).filter(Interior::has_getters);
+
+    if attrs.is_const && (shared.is_some() || interior.is_some()) {
+        return Error::new(
+            fin.span(),
+            "`const` getters are not supported for `Rc`/`Arc`, interior mutability and atomic fields",
+        )
+        .to_compile_error();
+    }
+
+    if attrs.is_const && matches!(attrs.modifier, Modifier::Move) {
+        return Error::new(
+            fin.span(),
+            "`const` getters can't take `self` by value, since the rest of the struct would be dropped",
+        )
+        .to_compile_error();
+    }
+
+    if let Some(pointer) = shared {
+        return pointer.getters(
+            &attrs.prefix,
+            &getter_name,
+            &attrs.mut_suffix,
+            field,
+            &attrs.method,
+        );
+    }
+
+    if let Some(interior) = interior {
+        return interior.getters(
+            &attrs.prefix,
+            &getter_name,
+            &attrs.mut_suffix,
+            field,
+            &attrs.method,
+        );
     }
 
+    getter_name = format_ident!("{}{}", attrs.prefix, getter_name);
+
     let modifier = &attrs.modifier.into();
 
-    generate_getter(&getter_name, &modifier, &fin, ty)
+    let docs = attrs
+        .method
+        .tokens(&format!("Returns the `{}`", fin), field);
+
+    let mut result = generate_getter(&getter_name, modifier, fin, ty, &docs, attrs.is_const);
+
+    if attrs.both {
+        let getter_mut_name = format_ident!("{}{}{}", attrs.prefix, name, attrs.mut_suffix);
+        let docs = attrs.method.tokens(
+            &format!("Returns a mutable reference to the `{}`", fin),
+            field,
+        );
+
+        result.extend(generate_getter(
+            &getter_mut_name,
+            &Modifier::MutRef.into(),
+            fin,
+            ty,
+            &docs,
+            attrs.is_const,
+        ));
+    }
+
+    result
 }
 
 /// Generates `set` `TokenStream` based on the provided `AccessorFieldAttrs`
@@ -74,6 +386,14 @@ pub fn process_set(mut attrs: AccessorFieldAttrs, field: &Field) -> TokenStream
     let fin = &field.ident.clone().unwrap();
     let ty = &field.ty;
 
+    if attrs.is_const {
+        return Error::new(
+            fin.span(),
+            "`const` is not supported by setters, since the assignment drops the old value",
+        )
+        .to_compile_error();
+    }
+
     if attrs.prefix.is_empty() {
         attrs.prefix = String::from("set_");
     }
@@ -84,7 +404,24 @@ pub fn process_set(mut attrs: AccessorFieldAttrs, field: &Field) -> TokenStream
         setter_name = format_ident!("{}", attrs.name);
     }
 
-    setter_name = format_ident!("{}{}", attrs.prefix, setter_name);
+    let name = setter_name.clone();
+    setter_name = format_ident!("{}{}{}", attrs.prefix, setter_name, attrs.suffix);
 
-    generate_setter(&setter_name, fin, ty)
+    let docs = attrs.method.tokens(&format!("Sets the `{}`", fin), field);
+
+    let interior = Interior::from_type(ty, attrs.ordering);
+    let mut result = generate_setter(
+        &setter_name,
+        fin,
+        ty,
+        &attrs.hooks,
+        interior.as_ref(),
+        &docs,
+    );
+
+    if let Some(interior) = interior {
+        result.extend(interior.modifiers(&name, field, &attrs.method));
+    }
+
+    result
 }
@@ -2,59 +2,142 @@
 //!
 //! This module contains the `StructAttrs` struct which represents struct-level attribute info
 
-use syn::{Token, punctuated::Punctuated};
+use syn::{Meta, Path, Token, Type, punctuated::Punctuated};
 
 use crate::accessors::AccessorArg;
 use crate::accessors::AccessorFieldAttrs;
+use crate::field_selection::FieldSelection;
+use crate::hook::Hook;
 use crate::modifier::Modifier;
 use crate::new_from_args::NewFromArgs;
+use crate::ordering::AtomicOrdering;
+use crate::selection::SelectionAttrs;
+use crate::track::Track;
 
 /// Represents a struct-level attribute info
 #[derive(Debug, Clone)]
 pub struct AccessorStructAttrs {
-    /// Fields to include
-    pub includes: Vec<String>,
-    /// Fields to exclude
-    pub excludes: Vec<String>,
+    /// Fields to include and exclude
+    pub selection: SelectionAttrs,
     /// Prefix
     pub prefix: String,
+    /// Suffix
+    pub suffix: String,
+    /// Whether to generate a `&mut self` getter besides the `&self` one
+    pub both: bool,
+    /// Suffix of the `&mut self` getter. The default value is `_mut`
+    pub mut_suffix: String,
     /// `self` modifier
     pub modifier: Modifier,
     /// Whether to include public fields or not
     pub include_pub: bool,
+    /// Change tracking mode of setters
+    pub track: Option<Track>,
+    /// Method called by setters after the assignment
+    pub notify: Option<Path>,
+    /// Method called by setters to validate the value
+    pub validate: Option<Path>,
+    /// Error type of the validator
+    pub error: Option<Type>,
+    /// Memory ordering of atomic accessors
+    pub ordering: AtomicOrdering,
+    /// Whether getters of `Rc`/`Arc` fields return the pointed value
+    pub shared: bool,
+    /// Trait implemented by the generated methods instead of the inherent impl
+    pub trait_path: Option<Path>,
+    /// Attributes added to every generated method
+    pub attrs: Vec<Meta>,
+    /// Whether to generate `const fn`
+    pub is_const: bool,
 }
 
 impl NewFromArgs<AccessorArg> for AccessorStructAttrs {
     fn new(punctuated: Punctuated<AccessorArg, Token![,]>) -> AccessorStructAttrs {
-        let mut includes = Vec::new();
-        let mut excludes = Vec::new();
+        let mut selection = SelectionAttrs::default();
         let mut prefix = String::new();
+        let mut suffix = String::new();
+        let mut both = false;
+        let mut mut_suffix = String::from("_mut");
         let mut modifier = Modifier::Ref;
         let mut include_pub = false;
+        let mut track = None;
+        let mut notify = None;
+        let mut validate = None;
+        let mut error = None;
+        let mut ordering = AtomicOrdering::default();
+        let mut shared = false;
+        let mut trait_path = None;
+        let mut attrs = Vec::new();
+        let mut is_const = false;
 
         for value in punctuated {
             match value {
-                AccessorArg::Includes(i) => includes = i,
-                AccessorArg::Excludes(e) => excludes = e,
+                AccessorArg::Selection(s) => selection.add(s),
                 AccessorArg::Prefix(p) => prefix = p,
+                AccessorArg::Suffix(s) => suffix = s,
+                AccessorArg::Both => both = true,
+                AccessorArg::MutSuffix(s) => mut_suffix = s,
                 AccessorArg::Modifier(m) => modifier = m,
                 AccessorArg::Pub => include_pub = true,
-                _ => {}
+                AccessorArg::Track(t) => track = Some(t),
+                AccessorArg::Notify(n) => notify = Some(n),
+                AccessorArg::Validate(v) => validate = Some(v),
+                AccessorArg::Error(e) => error = Some(e),
+                AccessorArg::Ordering(o) => ordering = o,
+                AccessorArg::Shared => shared = true,
+                AccessorArg::Trait(t) => trait_path = Some(t),
+                AccessorArg::Attrs(a) => attrs.extend(a),
+                AccessorArg::Inline(i) => attrs.push(i),
+                AccessorArg::Const => is_const = true,
+                // Field-level args, rejected by `AccessorArg::parse_in`
+                AccessorArg::Name(_) | AccessorArg::Map(_) | AccessorArg::Doc(_) => {}
             };
         }
 
         AccessorStructAttrs {
-            includes,
-            excludes,
+            selection,
             prefix,
+            suffix,
+            both,
+            mut_suffix,
             modifier,
             include_pub,
+            track,
+            notify,
+            validate,
+            error,
+            ordering,
+            shared,
+            trait_path,
+            attrs,
+            is_const,
         }
     }
 }
 
-impl Into<AccessorFieldAttrs> for AccessorStructAttrs {
-    fn into(self) -> AccessorFieldAttrs {
-        AccessorFieldAttrs::from_values(self.prefix, "".to_string(), self.modifier)
+impl FieldSelection for AccessorStructAttrs {
+    fn selection(&self) -> &SelectionAttrs {
+        &self.selection
+    }
+
+    fn include_pub(&self) -> bool {
+        self.include_pub
+    }
+}
+
+impl From<AccessorStructAttrs> for AccessorFieldAttrs {
+    fn from(val: AccessorStructAttrs) -> Self {
+        let mut attrs = AccessorFieldAttrs::from_values(val.prefix, "".to_string(), val.modifier);
+        attrs.hooks.notify = val.notify.map(Hook::Method);
+        attrs.hooks.validate = val.validate.map(Hook::Method);
+        attrs.hooks.error = val.error;
+        attrs.ordering = val.ordering;
+        attrs.shared = val.shared;
+        attrs.method.attrs = val.attrs;
+        attrs.is_const = val.is_const;
+        attrs.suffix = val.suffix;
+        attrs.both = val.both;
+        attrs.mut_suffix = val.mut_suffix;
+        attrs
     }
 }
@@ -2,7 +2,9 @@ mod accessor_arg;
 mod accessor_field_attrs;
 pub mod accessor_processor;
 mod accessor_struct_attrs;
+mod setter_hooks;
 
 pub use accessor_arg::*;
 pub use accessor_field_attrs::*;
 pub use accessor_struct_attrs::*;
+pub use setter_hooks::*;
@@ -0,0 +1,23 @@
+//! # setter_hooks
+//!
+//! This module contains the `SetterHooks` struct which holds the statements of a setter besides the assignment
+
+use proc_macro2::TokenStream;
+use syn::{Path, Type};
+
+use crate::hook::Hook;
+
+/// Statements of a generated setter besides the assignment
+#[derive(Debug, Clone, Default)]
+pub struct SetterHooks {
+    /// Function that transforms the value before it is stored, called as `map(value)`
+    pub map: Option<Path>,
+    /// Validator called with `&value`, the setter returns its error and leaves the field untouched
+    pub validate: Option<Hook>,
+    /// Error type of the validator. The default value is `String`
+    pub error: Option<Type>,
+    /// Statements that mark the field as dirty, executed before the assignment
+    pub mark_dirty: TokenStream,
+    /// Hook called after the assignment with the field name, old and new values
+    pub notify: Option<Hook>,
+}
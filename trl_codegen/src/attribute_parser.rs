@@ -3,27 +3,59 @@
 
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{Attribute, Fields, ItemStruct, Meta, Token, parse::Parse, punctuated::Punctuated};
+use syn::{
+    Attribute, Error, ItemStruct, Meta, Token,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+};
 
 use crate::{
     accessors::{
-        AccessorArg, AccessorFieldAttrs, AccessorStructAttrs,
-        accessor_processor::{process_get, process_getters, process_set, process_setters},
+        AccessorArg, AccessorFieldAttrs, AccessorStructAttrs, GET_ARG_NAMES, GETTERS_ARG_NAMES,
+        SET_ARG_NAMES, SETTERS_ARG_NAMES,
+        accessor_processor::{
+            process_get, process_getters, process_getters_trait, process_set, process_setters,
+            process_setters_field_enum,
+        },
+    },
+    compare::{
+        CompareArg, CompareStructAttrs, EQ_ARG_NAMES, HASH_ARG_NAMES, ORD_ARG_NAMES,
+        compare_processor::{process_eq, process_hash, process_ord},
     },
     constructor::{
         ConstructorArg, ConstructorStructAttrs, constructor_processor::process_constructor,
     },
+    debug::{DebugArg, DebugStructAttrs, debug_processor::process_debug},
+    default::default_processor::process_default,
+    delegate::{
+        DelegateFieldAttrs, DelegateMethod,
+        delegate_processor::{process_delegate, process_deref},
+    },
+    display::display_processor::process_display,
+    field_selection::check_field_selection,
+    method_registry::MethodRegistry,
     new_from_args::NewFromArgs,
+    partial::{
+        PartialArg, PartialStructAttrs,
+        partial_processor::{process_partial, process_partial_struct},
+    },
+    parts::{
+        PartsArg, PartsStructAttrs,
+        parts_processor::{process_parts, process_parts_impls},
+    },
+    pin::pin_processor::{pin_attribute, process_pin},
+    trl_attr::{TrlFieldArg, TrlFieldAttrs, TrlStructArg, TrlStructAttrs},
+    value::value_processor::{process_value, process_value_impls},
 };
 
 /// Parses `Attribute` args into a punctuated sequence of `Arg`
-pub fn parse_punctuated_attribute_args<TArg>(attribute: &Attribute) -> Punctuated<TArg, Token![,]>
+pub fn parse_punctuated_attribute_args<TArg>(
+    attribute: &Attribute,
+) -> syn::Result<Punctuated<TArg, Token![,]>>
 where
     TArg: Parse,
 {
-    attribute
-        .parse_args_with(Punctuated::<TArg, Token![,]>::parse_terminated)
-        .expect("error on parse: ")
+    attribute.parse_args_with(Punctuated::<TArg, Token![,]>::parse_terminated)
 }
 
 /// Creates attributes type with specified arguments
@@ -34,7 +66,7 @@ where
     T::new(punctuated)
 }
 
-/// Returns attribute name based on meta-type
+// Returns attribute name based on meta-type
 // pub fn match_attribute_name_attrs<T, TArgs>(attrs: &mut T, name: &mut String, attribute: &Attribute)
 // where
 //     T: NewFromArgs<TArgs>,
@@ -68,86 +100,308 @@ pub fn get_attribute_name(attribute: &Attribute) -> String {
             .get_ident()
             .expect(_expected_attribute_name)
             .to_string(),
-        _ => unreachable!(),
+        Meta::NameValue(nv) => nv
+            .path
+            .get_ident()
+            .expect(_expected_attribute_name)
+            .to_string(),
     }
 }
 
 /// This function loads args from `Attribute`
-pub fn load_args_from_attribute<T, TArgs>(attribute: &Attribute) -> T
+pub fn load_args_from_attribute<T, TArgs>(attribute: &Attribute) -> syn::Result<T>
 where
     T: NewFromArgs<TArgs>,
     TArgs: Parse,
 {
     if let Meta::List(_) = &attribute.meta {
-        let pun = parse_punctuated_attribute_args(attribute);
-        get_attrs_for_args::<T, TArgs>(pun)
+        let pun = parse_punctuated_attribute_args(attribute)?;
+        Ok(get_attrs_for_args::<T, TArgs>(pun))
     } else {
-        T::new(Punctuated::<TArgs, Token![,]>::new())
+        Ok(T::new(Punctuated::<TArgs, Token![,]>::new()))
     }
 }
 
+/// This function loads args from `Attribute`, which takes only the `names` arguments.
+/// Every argument is parsed with `parse_in`, which reports the arguments that are not in `names`
+pub fn load_args_in<T, TArgs>(
+    attribute: &Attribute,
+    names: &[&str],
+    parse_in: fn(ParseStream, &str, &[&str]) -> syn::Result<TArgs>,
+) -> syn::Result<T>
+where
+    T: NewFromArgs<TArgs>,
+{
+    let Meta::List(_) = &attribute.meta else {
+        return Ok(T::new(Punctuated::new()));
+    };
+
+    let name = get_attribute_name(attribute);
+
+    let args = attribute.parse_args_with(|input: ParseStream| {
+        let mut args = Punctuated::<TArgs, Token![,]>::new();
+
+        while !input.is_empty() {
+            args.push_value(parse_in(input, &name, names)?);
+
+            if input.is_empty() {
+                break;
+            }
+
+            args.push_punct(input.parse()?);
+        }
+
+        Ok(args)
+    })?;
+
+    Ok(get_attrs_for_args::<T, TArgs>(args))
+}
+
 /// Creates struct level attributes implementation
+///
+/// Methods are written to `stream`, which is placed inside the `impl` block of the struct,
+/// trait impls are written to `items`, which is placed next to it.
+/// Methods with a name already generated by another attribute are reported through `registry`
 pub fn process_struct_attributes(
     attributes: &Vec<Attribute>,
     input: &ItemStruct,
     stream: &mut TokenStream,
+    items: &mut TokenStream,
+    registry: &mut MethodRegistry,
 ) {
     for attribute in attributes {
         let name = get_attribute_name(attribute);
+        let mut methods = TokenStream::new();
+        let source = format!("`#[{}]`", name);
 
-        match name.as_str() {
-            "getters" => {
-                let attrs = load_args_from_attribute::<AccessorStructAttrs, AccessorArg>(attribute);
-                // match_attribute_name_attrs::<AccessorStructAttrs, AccessorArg>(
-                //     &mut attrs, &mut name, &attribute,
-                // );
-                stream.extend(process_getters(attrs, input))
-            }
-            "setters" => {
-                let attrs = load_args_from_attribute::<AccessorStructAttrs, AccessorArg>(attribute);
-                // match_attribute_name_attrs::<AccessorStructAttrs, AccessorArg>(
-                //     &mut attrs, &mut name, &attribute,
-                // );
-                stream.extend(process_setters(attrs, input))
-            }
-            "constructor" => {
-                let attrs =
-                    load_args_from_attribute::<ConstructorStructAttrs, ConstructorArg>(attribute);
-
-                stream.extend(process_constructor(attrs, input));
-            }
-            _ => {}
-        };
-    }
-}
-
-/// Creates field-level attributes implementation
-pub fn process_field_attributes(fields: &Fields, stream: &mut TokenStream) {
-    fields.iter().for_each(|field| {
-        let attributes = &field.attrs;
-
-        for attribute in attributes {
-            let name = get_attribute_name(attribute);
+        let result = (|| -> syn::Result<()> {
+            let stream = &mut methods;
 
             match name.as_str() {
-                "get" => {
-                    let attrs =
-                        load_args_from_attribute::<AccessorFieldAttrs, AccessorArg>(attribute);
-                    // match_attribute_name_attrs::<AccessorFieldAttrs, AccessorArg>(
+                "getters" => {
+                    let attrs = load_args_in::<AccessorStructAttrs, AccessorArg>(
+                        attribute,
+                        GETTERS_ARG_NAMES,
+                        AccessorArg::parse_in,
+                    )?;
+                    items.extend(check_field_selection(&attrs, &input.fields)?);
+                    // match_attribute_name_attrs::<AccessorStructAttrs, AccessorArg>(
                     //     &mut attrs, &mut name, &attribute,
                     // );
-                    stream.extend(process_get(attrs, field))
+                    if attrs.trait_path.is_some() {
+                        let define_trait = input
+                            .attrs
+                            .iter()
+                            .any(|attr| attr.path().is_ident("trl_trait"));
+
+                        items.extend(process_getters_trait(attrs, input, define_trait))
+                    } else {
+                        stream.extend(process_getters(attrs, input))
+                    }
                 }
-                "set" => {
-                    let attrs =
-                        load_args_from_attribute::<AccessorFieldAttrs, AccessorArg>(attribute);
-                    // match_attribute_name_attrs::<AccessorFieldAttrs, AccessorArg>(
+                "setters" => {
+                    let attrs = load_args_in::<AccessorStructAttrs, AccessorArg>(
+                        attribute,
+                        SETTERS_ARG_NAMES,
+                        AccessorArg::parse_in,
+                    )?;
+                    items.extend(check_field_selection(&attrs, &input.fields)?);
+                    // match_attribute_name_attrs::<AccessorStructAttrs, AccessorArg>(
                     //     &mut attrs, &mut name, &attribute,
                     // );
-                    stream.extend(process_set(attrs, field))
+                    if attrs.track.is_some() {
+                        items.extend(process_setters_field_enum(attrs.clone(), input));
+                    }
+
+                    stream.extend(process_setters(attrs, input))
+                }
+                "constructor" => {
+                    let attrs = load_args_from_attribute::<ConstructorStructAttrs, ConstructorArg>(
+                        attribute,
+                    )?;
+
+                    stream.extend(process_constructor(attrs, input));
+                }
+                "debug" => {
+                    let attrs = load_args_from_attribute::<DebugStructAttrs, DebugArg>(attribute)?;
+                    items.extend(check_field_selection(&attrs, &input.fields)?);
+
+                    items.extend(process_debug(attrs, input));
+                }
+                "display" => {
+                    items.extend(process_display(attribute, input));
+                }
+                "eq" => {
+                    let attrs = load_args_in::<CompareStructAttrs, CompareArg>(
+                        attribute,
+                        EQ_ARG_NAMES,
+                        CompareArg::parse_in,
+                    )?;
+                    items.extend(check_field_selection(&attrs, &input.fields)?);
+
+                    items.extend(process_eq(attrs, input));
+                }
+                "hash" => {
+                    let attrs = load_args_in::<CompareStructAttrs, CompareArg>(
+                        attribute,
+                        HASH_ARG_NAMES,
+                        CompareArg::parse_in,
+                    )?;
+                    items.extend(check_field_selection(&attrs, &input.fields)?);
+
+                    items.extend(process_hash(attrs, input));
+                }
+                "ord" => {
+                    let attrs = load_args_in::<CompareStructAttrs, CompareArg>(
+                        attribute,
+                        ORD_ARG_NAMES,
+                        CompareArg::parse_in,
+                    )?;
+                    items.extend(check_field_selection(&attrs, &input.fields)?);
+
+                    items.extend(process_ord(attrs, input));
+                }
+                "partial" => {
+                    let attrs =
+                        load_args_from_attribute::<PartialStructAttrs, PartialArg>(attribute)?;
+                    items.extend(check_field_selection(&attrs, &input.fields)?);
+
+                    stream.extend(process_partial(attrs.clone(), input));
+                    items.extend(process_partial_struct(attrs, input));
+                }
+                "parts" => {
+                    let attrs = load_args_from_attribute::<PartsStructAttrs, PartsArg>(attribute)?;
+                    items.extend(check_field_selection(&attrs, &input.fields)?);
+
+                    stream.extend(process_parts(attrs.clone(), input));
+                    items.extend(process_parts_impls(attrs, input));
+                }
+                "trl" => {
+                    let attrs =
+                        load_args_from_attribute::<TrlStructAttrs, TrlStructArg>(attribute)?;
+
+                    if attrs.default {
+                        items.extend(process_default(input));
+                    }
+                }
+                "trl_trait" => {
+                    let has_trait = input.attrs.iter().any(|attr| {
+                        attr.path().is_ident("getters")
+                            && load_args_from_attribute::<AccessorStructAttrs, AccessorArg>(attr)
+                                .is_ok_and(|attrs| attrs.trait_path.is_some())
+                    });
+
+                    if !has_trait {
+                        items.extend(
+                            Error::new_spanned(
+                                attribute,
+                                "`#[trl_trait]` requires `#[getters(trait = ...)]`",
+                            )
+                            .to_compile_error(),
+                        );
+                    }
+                }
+                "value" => {
+                    stream.extend(process_value(input));
+                    items.extend(process_value_impls(input));
                 }
                 _ => {}
             };
+
+            Ok(())
+        })();
+
+        if let Err(e) = result {
+            items.extend(e.to_compile_error());
+        }
+
+        stream.extend(registry.register(methods, source, attribute));
+    }
+}
+
+/// Creates field-level attributes implementation
+///
+/// Methods are written to `stream`, trait impls are written to `items`.
+/// Methods with a name already generated by another attribute are reported through `registry`
+pub fn process_field_attributes(
+    input: &ItemStruct,
+    stream: &mut TokenStream,
+    items: &mut TokenStream,
+    registry: &mut MethodRegistry,
+) {
+    let mut has_deref = false;
+
+    input.fields.iter().for_each(|field| {
+        let attributes = &field.attrs;
+        let field_name = field.ident.clone().expect("Error, expected named field");
+
+        for attribute in attributes {
+            let name = get_attribute_name(attribute);
+            let mut methods = TokenStream::new();
+            let source = format!("`#[{}]` on `{}`", name, field_name);
+
+            let result = (|| -> syn::Result<()> {
+                let stream = &mut methods;
+
+                match name.as_str() {
+                    "get" => {
+                        let attrs = load_args_in::<AccessorFieldAttrs, AccessorArg>(
+                            attribute,
+                            GET_ARG_NAMES,
+                            AccessorArg::parse_in,
+                        )?;
+                        // match_attribute_name_attrs::<AccessorFieldAttrs, AccessorArg>(
+                        //     &mut attrs, &mut name, &attribute,
+                        // );
+                        stream.extend(process_get(attrs, field))
+                    }
+                    "set" => {
+                        let attrs = load_args_in::<AccessorFieldAttrs, AccessorArg>(
+                            attribute,
+                            SET_ARG_NAMES,
+                            AccessorArg::parse_in,
+                        )?;
+                        // match_attribute_name_attrs::<AccessorFieldAttrs, AccessorArg>(
+                        //     &mut attrs, &mut name, &attribute,
+                        // );
+                        stream.extend(process_set(attrs, field))
+                    }
+                    "delegate" => {
+                        let attrs = load_args_from_attribute::<DelegateFieldAttrs, DelegateMethod>(
+                            attribute,
+                        )?;
+
+                        stream.extend(process_delegate(attrs, field))
+                    }
+                    "trl" => {
+                        load_args_from_attribute::<TrlFieldAttrs, TrlFieldArg>(attribute)?;
+                    }
+                    "deref" => {
+                        if has_deref {
+                            items.extend(
+                                Error::new_spanned(
+                                    attribute,
+                                    "Only one field can be marked with `#[deref]`",
+                                )
+                                .to_compile_error(),
+                            );
+                            return Ok(());
+                        }
+
+                        has_deref = true;
+                        items.extend(process_deref(field, input))
+                    }
+                    _ => {}
+                };
+
+                Ok(())
+            })();
+
+            if let Err(e) = result {
+                items.extend(e.to_compile_error());
+            }
+
+            stream.extend(registry.register(methods, source, attribute));
         }
     });
 }
@@ -155,17 +409,129 @@ pub fn process_field_attributes(fields: &Fields, stream: &mut TokenStream) {
 /// Generates impls
 pub fn generate_impl_for_struct(input: &ItemStruct) -> proc_macro::TokenStream {
     let mut elements = TokenStream::new();
+    let mut items = TokenStream::new();
+
+    let mut registry = MethodRegistry::default();
 
-    process_struct_attributes(&input.attrs, &input, &mut elements);
+    process_struct_attributes(
+        &input.attrs,
+        input,
+        &mut elements,
+        &mut items,
+        &mut registry,
+    );
 
-    process_field_attributes(&input.fields, &mut elements);
+    process_field_attributes(input, &mut elements, &mut items, &mut registry);
+
+    if let Some(pin) = pin_attribute(input) {
+        let mut methods = TokenStream::new();
+        process_pin(input, &mut methods, &mut items);
+
+        elements.extend(registry.register(methods, String::from("`#[pin]`"), pin));
+    }
+
+    items.extend(registry.errors());
 
     let struct_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
 
     quote! {
-        impl #struct_name {
+        impl #impl_generics #struct_name #ty_generics #where_clause {
             #elements
         }
+
+        #items
     }
     .into()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    fn struct_error(attribute: Attribute, names: &[&str]) -> String {
+        match load_args_in::<AccessorStructAttrs, AccessorArg>(
+            &attribute,
+            names,
+            AccessorArg::parse_in,
+        ) {
+            Ok(_) => String::new(),
+            Err(e) => e.to_string(),
+        }
+    }
+
+    fn field_error(attribute: Attribute, names: &[&str]) -> String {
+        match load_args_in::<AccessorFieldAttrs, AccessorArg>(
+            &attribute,
+            names,
+            AccessorArg::parse_in,
+        ) {
+            Ok(_) => String::new(),
+            Err(e) => e.to_string(),
+        }
+    }
+
+    #[test]
+    fn accessor_args_position_test() {
+        assert!(
+            struct_error(
+                parse_quote!(#[getters(mut ref, both, include = [id])]),
+                GETTERS_ARG_NAMES
+            )
+            .is_empty()
+        );
+        assert!(field_error(parse_quote!(#[set(name = id, map = f)]), SET_ARG_NAMES).is_empty());
+
+        let error = struct_error(parse_quote!(#[setters(name = x)]), SETTERS_ARG_NAMES);
+        assert!(
+            error.starts_with("`name` is not an argument of `#[setters]`. Valid args: `includes`")
+        );
+        assert!(!error.contains("`map`"));
+
+        let error = field_error(parse_quote!(#[get(include = [id])]), GET_ARG_NAMES);
+        assert!(
+            error.starts_with("`include` is not an argument of `#[get]`. Valid args: `prefix`")
+        );
+        assert!(!error.contains("`includes`"));
+
+        let error = field_error(parse_quote!(#[set(pub)]), SET_ARG_NAMES);
+        assert!(error.starts_with("`pub` is not an argument of `#[set]`"));
+
+        let error = struct_error(parse_quote!(#[setters(map = lower)]), SETTERS_ARG_NAMES);
+        assert!(error.contains("use `#[set(map = ...)]` on the field"));
+
+        let error = struct_error(parse_quote!(#[getters(doc = "Id")]), GETTERS_ARG_NAMES);
+        assert!(error.starts_with("`doc` is not an argument of `#[getters]`. `doc` replaces"));
+
+        let error = struct_error(parse_quote!(#[getters(prefx = get_)]), GETTERS_ARG_NAMES);
+        assert!(error.starts_with("Unknown arg `prefx`. Did you mean `prefix`?"));
+        assert!(!error.contains("`track`"));
+    }
+
+    fn compare_error(attribute: Attribute, names: &[&str]) -> String {
+        match load_args_in::<CompareStructAttrs, CompareArg>(
+            &attribute,
+            names,
+            CompareArg::parse_in,
+        ) {
+            Ok(_) => String::new(),
+            Err(e) => e.to_string(),
+        }
+    }
+
+    #[test]
+    fn compare_args_position_test() {
+        assert!(
+            compare_error(parse_quote!(#[ord(by = [id desc], partial)]), ORD_ARG_NAMES).is_empty()
+        );
+        assert!(compare_error(parse_quote!(#[eq(partial)]), EQ_ARG_NAMES).is_empty());
+
+        let error = compare_error(parse_quote!(#[eq(by = [id])]), EQ_ARG_NAMES);
+        assert!(error.starts_with("`by` is not an argument of `#[eq]`"));
+
+        let error = compare_error(parse_quote!(#[hash(partial)]), HASH_ARG_NAMES);
+        assert!(error.starts_with("`partial` is not an argument of `#[hash]`"));
+        assert!(!error.contains("`by`"));
+    }
+}
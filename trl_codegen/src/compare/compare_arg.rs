@@ -0,0 +1,142 @@
+//! # compare_arg
+//! This module contains the `CompareArg` enum which represents a single argument of the `eq`, `hash` and `ord` attributes
+//!
+
+use syn::{
+    Error, Ident, Result, Token, bracketed,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+};
+
+use crate::{
+    helpers::{check_arg_name, unknown_arg_error},
+    selection::SelectionArg,
+};
+
+/// Names of all `eq`, `hash` and `ord` arguments
+const ARG_NAMES: &[&str] = &[
+    "includes",
+    "excludes",
+    "types",
+    "exclude_types",
+    "groups",
+    "exclude_groups",
+    "by",
+    "partial",
+];
+
+/// Names of `#[eq]` arguments
+pub const EQ_ARG_NAMES: &[&str] = &[
+    "includes",
+    "excludes",
+    "types",
+    "exclude_types",
+    "groups",
+    "exclude_groups",
+    "partial",
+];
+
+/// Names of `#[hash]` arguments
+pub const HASH_ARG_NAMES: &[&str] = &[
+    "includes",
+    "excludes",
+    "types",
+    "exclude_types",
+    "groups",
+    "exclude_groups",
+];
+
+/// Names of `#[ord]` arguments
+pub const ORD_ARG_NAMES: &[&str] = &[
+    "includes",
+    "excludes",
+    "types",
+    "exclude_types",
+    "groups",
+    "exclude_groups",
+    "by",
+    "partial",
+];
+
+/// A field in the `by=[...]` list of the `ord` attribute
+#[derive(Debug, Clone)]
+pub struct OrderKey {
+    /// Field name
+    pub field: Ident,
+    /// Whether the field is compared in reverse order
+    pub desc: bool,
+}
+
+impl Parse for OrderKey {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let field = input.parse::<Ident>()?;
+
+        let desc = if input.peek(Ident) {
+            let order = input.parse::<Ident>()?;
+
+            match order.to_string().as_str() {
+                "asc" => false,
+                "desc" => true,
+                _ => return Err(Error::new(order.span(), "expected `asc` or `desc`")),
+            }
+        } else {
+            false
+        };
+
+        Ok(OrderKey { field, desc })
+    }
+}
+
+/// Enum CompareArg represents a single argument of the `eq`, `hash` and `ord` attributes
+pub enum CompareArg {
+    /// Fields to include or exclude, e.g. `includes = [...]` or `types = [...]`
+    Selection(SelectionArg),
+    /// Fields to compare in the specified order
+    By(Vec<OrderKey>),
+    /// Generate only `PartialEq`/`PartialOrd`
+    Partial,
+}
+
+impl CompareArg {
+    /// Parses an argument of the `attribute`, which takes only the `names` arguments
+    pub fn parse_in(input: ParseStream, attribute: &str, names: &[&str]) -> Result<Self> {
+        check_arg_name(input, attribute, names, ARG_NAMES)?;
+
+        input.parse()
+    }
+
+    /// This method parses the `by = [a, b desc]` construction into an `Arg`
+    pub fn parse_by(input: ParseStream) -> Result<Self> {
+        input.parse::<Ident>()?;
+        input.parse::<Token![=]>()?;
+
+        let content;
+        bracketed!(content in input);
+
+        let keys = Punctuated::<OrderKey, Token![,]>::parse_terminated(&content)?;
+
+        Ok(CompareArg::By(keys.into_iter().collect()))
+    }
+}
+
+impl Parse for CompareArg {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if SelectionArg::peek(input) {
+            return Ok(CompareArg::Selection(input.parse()?));
+        }
+
+        let ident = input.fork().parse::<Ident>().ok();
+
+        let arg = match ident {
+            Some(ident) if ident == "by" => CompareArg::parse_by(input)?,
+            Some(ident) if ident == "partial" && !input.peek2(Token![=]) => {
+                input.parse::<Ident>()?;
+                CompareArg::Partial
+            }
+            Some(ident) => return Err(unknown_arg_error(&ident, ARG_NAMES)),
+            None => return Err(Error::new(input.span(), "Could not parse arg")),
+        };
+
+        Ok(arg)
+    }
+}
@@ -0,0 +1,156 @@
+//! # compare_processor
+//! This module contains functions that generate `PartialEq`, `Eq`, `Hash`, `PartialOrd` and `Ord` impls
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{ItemStruct, Result};
+
+use crate::{
+    compare::{CompareStructAttrs, OrderKey},
+    helpers::{fill_includes_if_empty, should_field_be_added, unknown_field_error},
+};
+
+/// Generates `PartialEq` and `Eq` impls
+pub fn process_eq(mut attrs: CompareStructAttrs, input: &ItemStruct) -> TokenStream {
+    fill_includes_if_empty(&mut attrs.selection.includes, &input.fields);
+
+    let struct_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let fields = match selected_fields(&attrs, input) {
+        Ok(keys) => keys.into_iter().map(|k| k.field).collect::<Vec<_>>(),
+        Err(e) => return e.to_compile_error(),
+    };
+
+    let eq = if attrs.partial {
+        TokenStream::new()
+    } else {
+        quote! {
+            impl #impl_generics ::std::cmp::Eq for #struct_name #ty_generics #where_clause {}
+        }
+    };
+
+    quote! {
+        impl #impl_generics ::std::cmp::PartialEq for #struct_name #ty_generics #where_clause {
+            fn eq(&self, other: &Self) -> bool {
+                true #(&& self.#fields == other.#fields)*
+            }
+        }
+
+        #eq
+    }
+}
+
+/// Generates `Hash` impl
+pub fn process_hash(mut attrs: CompareStructAttrs, input: &ItemStruct) -> TokenStream {
+    fill_includes_if_empty(&mut attrs.selection.includes, &input.fields);
+
+    let struct_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let fields = match selected_fields(&attrs, input) {
+        Ok(keys) => keys.into_iter().map(|k| k.field).collect::<Vec<_>>(),
+        Err(e) => return e.to_compile_error(),
+    };
+
+    quote! {
+        impl #impl_generics ::std::hash::Hash for #struct_name #ty_generics #where_clause {
+            fn hash<__H: ::std::hash::Hasher>(&self, state: &mut __H) {
+                #(::std::hash::Hash::hash(&self.#fields, state);)*
+            }
+        }
+    }
+}
+
+/// Generates `PartialOrd` and `Ord` impls
+pub fn process_ord(mut attrs: CompareStructAttrs, input: &ItemStruct) -> TokenStream {
+    fill_includes_if_empty(&mut attrs.selection.includes, &input.fields);
+
+    let struct_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let keys = match selected_fields(&attrs, input) {
+        Ok(keys) => keys,
+        Err(e) => return e.to_compile_error(),
+    };
+
+    // `desc` fields are compared with swapped operands
+    let operands = keys
+        .iter()
+        .map(|OrderKey { field, desc }| {
+            if *desc {
+                (quote! { other.#field }, quote! { self.#field })
+            } else {
+                (quote! { self.#field }, quote! { other.#field })
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if attrs.partial {
+        let comparisons = operands.iter().map(|(left, right)| {
+            quote! {
+                match ::std::cmp::PartialOrd::partial_cmp(&#left, &#right) {
+                    ::std::option::Option::Some(::std::cmp::Ordering::Equal) => {}
+                    ordering => return ordering,
+                }
+            }
+        });
+
+        return quote! {
+            impl #impl_generics ::std::cmp::PartialOrd for #struct_name #ty_generics #where_clause {
+                fn partial_cmp(&self, other: &Self) -> ::std::option::Option<::std::cmp::Ordering> {
+                    #(#comparisons)*
+                    ::std::option::Option::Some(::std::cmp::Ordering::Equal)
+                }
+            }
+        };
+    }
+
+    let comparisons = operands.iter().map(|(left, right)| {
+        quote! { .then_with(|| ::std::cmp::Ord::cmp(&#left, &#right)) }
+    });
+
+    quote! {
+        impl #impl_generics ::std::cmp::PartialOrd for #struct_name #ty_generics #where_clause {
+            fn partial_cmp(&self, other: &Self) -> ::std::option::Option<::std::cmp::Ordering> {
+                ::std::option::Option::Some(::std::cmp::Ord::cmp(self, other))
+            }
+        }
+
+        impl #impl_generics ::std::cmp::Ord for #struct_name #ty_generics #where_clause {
+            fn cmp(&self, other: &Self) -> ::std::cmp::Ordering {
+                ::std::cmp::Ordering::Equal #(#comparisons)*
+            }
+        }
+    }
+}
+
+/// Returns fields listed in `by`, or fields selected with `includes` and `excludes` if `by` is empty
+fn selected_fields(attrs: &CompareStructAttrs, input: &ItemStruct) -> Result<Vec<OrderKey>> {
+    if attrs.by.is_empty() {
+        return Ok(input
+            .fields
+            .iter()
+            .filter(|field| should_field_be_added(attrs, field))
+            .map(|field| OrderKey {
+                field: field.ident.clone().expect("Error, expected named field"),
+                desc: false,
+            })
+            .collect());
+    }
+
+    let field_names = input
+        .fields
+        .iter()
+        .filter_map(|field| field.ident.as_ref().map(ToString::to_string))
+        .collect::<Vec<_>>();
+    let field_names = field_names.iter().map(String::as_str).collect::<Vec<_>>();
+
+    for key in &attrs.by {
+        if !field_names.contains(&key.field.to_string().as_str()) {
+            return Err(unknown_field_error(&key.field, &field_names));
+        }
+    }
+
+    Ok(attrs.by.clone())
+}
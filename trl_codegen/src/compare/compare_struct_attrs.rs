@@ -0,0 +1,55 @@
+//! # compare_struct_attrs
+//! This module contains the `CompareStructAttrs` struct which represents the args of the `eq`, `hash` and `ord` attributes
+//!
+
+use syn::{Token, punctuated::Punctuated};
+
+use crate::{
+    compare::{CompareArg, OrderKey},
+    field_selection::FieldSelection,
+    new_from_args::NewFromArgs,
+    selection::SelectionAttrs,
+};
+
+/// Struct `CompareStructAttrs` represents the args of the `eq`, `hash` and `ord` attributes
+pub struct CompareStructAttrs {
+    /// Fields to include and exclude
+    pub selection: SelectionAttrs,
+    /// Fields to compare in the specified order, overrides `includes` and `excludes`
+    pub by: Vec<OrderKey>,
+    /// Generate only `PartialEq`/`PartialOrd`
+    pub partial: bool,
+}
+
+impl NewFromArgs<CompareArg> for CompareStructAttrs {
+    fn new(args: Punctuated<CompareArg, Token![,]>) -> Self {
+        let mut selection = SelectionAttrs::default();
+        let mut by = Vec::new();
+        let mut partial = false;
+
+        for value in args {
+            match value {
+                CompareArg::Selection(s) => selection.add(s),
+                CompareArg::By(b) => by = b,
+                CompareArg::Partial => partial = true,
+            }
+        }
+
+        Self {
+            selection,
+            by,
+            partial,
+        }
+    }
+}
+
+impl FieldSelection for CompareStructAttrs {
+    fn selection(&self) -> &SelectionAttrs {
+        &self.selection
+    }
+
+    // Public fields are a part of the value too
+    fn include_pub(&self) -> bool {
+        true
+    }
+}
@@ -0,0 +1,6 @@
+mod compare_arg;
+pub mod compare_processor;
+mod compare_struct_attrs;
+
+pub use compare_arg::*;
+pub use compare_struct_attrs::*;
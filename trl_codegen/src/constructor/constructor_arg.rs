@@ -3,17 +3,21 @@
 //!
 
 use syn::{
-    Error, Expr, ExprCall, ExprLit, ExprPath, Lit, LitStr, MetaNameValue, PatLit, Result,
+    Error, Expr, ExprLit, Ident, Lit, Meta, MetaNameValue, Result, Token, parenthesized,
     parse::{Parse, ParseStream},
     punctuated::Punctuated,
     spanned::Spanned,
-    token::Comma,
+    token::Paren,
 };
 
 const PUBLIC_VISIBILITY: &str = "pub";
 const PRIVATE_VISIBILITY: &str = "private";
 
 use crate::constructor::constructor_visibility::ConstructorVisibility;
+use crate::helpers::unknown_arg_error;
+
+/// Names of all constructor arguments, listed by the unknown argument error
+const ARG_NAMES: &[&str] = &["name", "visibility", "doc", "attrs", "inline", "const"];
 
 /// Enum ConstructorArg represents a single argument of a constructor attribute
 pub enum ConstructorArg {
@@ -21,17 +25,24 @@ pub enum ConstructorArg {
     Name(String),
     /// The constructor visibility. The default value is `ConstructorVisibility::Pub`
     Visibility(ConstructorVisibility),
+    /// The constructor docs, replaces the generated ones
+    Doc(String),
+    /// Attributes added to the constructor, e.g. `attrs(must_use)`
+    Attrs(Vec<Meta>),
+    /// `inline` or `inline(...)` attribute added to the constructor
+    Inline(Box<Meta>),
+    /// Generate `const fn`
+    Const,
 }
 
 impl ConstructorArg {
     pub fn nv_to_arg(nv: &MetaNameValue) -> Result<Self> {
-        match nv
+        let ident = nv
             .path
             .get_ident()
-            .expect("Wrong name. Expected Identifier")
-            .to_string()
-            .as_str()
-        {
+            .ok_or_else(|| Error::new(nv.path.span(), "Wrong name. Expected identifier"))?;
+
+        match ident.to_string().as_str() {
             "name" => Ok(ConstructorArg::Name(ConstructorArg::string_from_nv_value(
                 &nv.value,
             )?)),
@@ -40,7 +51,11 @@ impl ConstructorArg {
                 ConstructorArg::visibility_from_nv_value(&nv.value)?,
             )),
 
-            _ => Result::Err(Error::new(nv.span(), "Unknown arg name")),
+            "doc" => Ok(ConstructorArg::Doc(ConstructorArg::lit_from_nv_value(
+                &nv.value,
+            )?)),
+
+            _ => Err(unknown_arg_error(ident, ARG_NAMES)),
         }
     }
 
@@ -53,25 +68,21 @@ impl ConstructorArg {
                 .to_string());
         }
 
-        return Err(Error::new(e.span(), "Error, expected string"));
+        Err(Error::new(e.span(), "Error, expected string"))
     }
 
-    pub fn visibility_parse_pub(e: String, p: &ExprPath) -> Result<ConstructorVisibility> {
-        let path_text = p
-            .path
-            .get_ident()
-            .expect("Error, expected path")
-            .to_string();
-        if path_text == PUBLIC_VISIBILITY {
-            return Ok(ConstructorVisibility::Pub);
-        } else if path_text == PRIVATE_VISIBILITY {
-            return Ok(ConstructorVisibility::Private);
-        } else {
-            return Err(Error::new(e.span(), e));
+    pub fn lit_from_nv_value(e: &Expr) -> Result<String> {
+        if let Expr::Lit(ExprLit {
+            lit: Lit::Str(s), ..
+        }) = e
+        {
+            return Ok(s.value());
         }
+
+        Err(Error::new(e.span(), "Error, expected string literal"))
     }
 
-    pub fn visibility_parse_pub_path(e: String, v: &String) -> Result<ConstructorVisibility> {
+    pub fn visibility_parse_pub_path(e: String, v: &str) -> Result<ConstructorVisibility> {
         if v.starts_with("pub(") && v.ends_with(")") {
             let path = &v["pub(".len()..v.len() - 1];
 
@@ -83,19 +94,17 @@ impl ConstructorArg {
 
     pub fn visibility_from_nv_value(e: &Expr) -> Result<ConstructorVisibility> {
         let _error_unexpected_symbol = format!(
-            "Error, expected one of: \"{}\", \"{}\", \"{}\"",
-            PUBLIC_VISIBILITY,
-            PRIVATE_VISIBILITY,
-            format!("{}(path)", PUBLIC_VISIBILITY)
+            "Error, expected one of: \"{}\", \"{}\", \"{}(path)\"",
+            PUBLIC_VISIBILITY, PRIVATE_VISIBILITY, PUBLIC_VISIBILITY
         );
 
         if let Expr::Lit(ExprLit {
             lit: Lit::Str(s), ..
         }) = e
         {
-            if &s.value() == PUBLIC_VISIBILITY {
+            if s.value() == PUBLIC_VISIBILITY {
                 Ok(ConstructorVisibility::Pub)
-            } else if &s.value() == PRIVATE_VISIBILITY {
+            } else if s.value() == PRIVATE_VISIBILITY {
                 Ok(ConstructorVisibility::Private)
             } else {
                 ConstructorArg::visibility_parse_pub_path(_error_unexpected_symbol, &s.value())
@@ -116,14 +125,32 @@ impl ConstructorArg {
 
 impl Parse for ConstructorArg {
     fn parse(input: ParseStream) -> Result<Self> {
-        let arg = if let Ok(nv) = input.parse::<MetaNameValue>() {
-            ConstructorArg::nv_to_arg(&nv)?
-        } else {
-            return Result::Err(Error::new(
-                input.span(),
-                "Could not parse arg. Expected name-value",
-            ));
-        };
+        let arg =
+            if input.peek(Ident) && input.peek2(Paren) && input.fork().parse::<Ident>()? == "attrs"
+            {
+                input.parse::<Ident>()?;
+                let content;
+                parenthesized!(content in input);
+                ConstructorArg::Attrs(
+                    Punctuated::<Meta, Token![,]>::parse_terminated(&content)?
+                        .into_iter()
+                        .collect(),
+                )
+            } else if input.peek(Token![const]) {
+                input.parse::<Token![const]>()?;
+                ConstructorArg::Const
+            } else if input.peek(Ident) && input.fork().parse::<Ident>()? == "inline" {
+                ConstructorArg::Inline(Box::new(input.parse::<Meta>()?))
+            } else if input.peek(Ident) && !input.peek2(Token![=]) {
+                return Err(unknown_arg_error(&input.parse::<Ident>()?, ARG_NAMES));
+            } else if let Ok(nv) = input.parse::<MetaNameValue>() {
+                ConstructorArg::nv_to_arg(&nv)?
+            } else {
+                return Result::Err(Error::new(
+                    input.span(),
+                    "Could not parse arg. Expected name-value",
+                ));
+            };
 
         Ok(arg)
     }
@@ -1,9 +1,16 @@
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
-use syn::ItemStruct;
+use syn::{Error, ItemStruct};
 
-use crate::constructor::{
-    constructor_struct_attrs::ConstructorStructAttrs, constructor_visibility::ConstructorVisibility,
+use crate::{
+    attribute_parser::load_args_from_attribute,
+    constructor::{
+        constructor_struct_attrs::ConstructorStructAttrs,
+        constructor_visibility::ConstructorVisibility,
+    },
+    default::default_processor::field_default,
+    helpers::{cfg_attributes, field_docs_line, has_attribute},
+    trl_attr::{TrlFieldArg, TrlFieldAttrs},
 };
 
 pub fn process_constructor(attrs: ConstructorStructAttrs, input: &ItemStruct) -> TokenStream {
@@ -20,21 +27,87 @@ pub fn process_constructor(attrs: ConstructorStructAttrs, input: &ItemStruct) ->
 
     let mut constructor_args = TokenStream::new();
     let mut constructor_values = TokenStream::new();
-    input.fields.iter().for_each(|field| {
+    let mut arg_docs = Vec::new();
+    for field in input.fields.iter() {
         let field_name = field.clone().ident.expect("Error, expected named field");
         let field_ty = field.clone().ty;
+        // Fields behind `#[cfg]` are only present in the constructor with the same config
+        let cfg = cfg_attributes(field);
+
+        // `Default::default()` can't be called in `const fn`
+        if attrs.is_const
+            && let Some(default) = field.attrs.iter().find(|a| {
+                a.path().is_ident("trl")
+                    && load_args_from_attribute::<TrlFieldAttrs, TrlFieldArg>(a)
+                        .is_ok_and(|attrs| matches!(attrs.default, Some(None)))
+            })
+        {
+            return Error::new_spanned(
+                default,
+                "`const` constructor requires a const default value, e.g. `#[trl(default = 0)]`",
+            )
+            .to_compile_error();
+        }
+
+        // Fields with `#[trl(default)]` are initialized with their default value
+        if let Some(value) = field_default(field) {
+            constructor_values.extend(quote! {
+                #(#cfg)*
+                #field_name: #value,
+            });
+            continue;
+        }
 
         constructor_args.extend(quote! {
+            #(#cfg)*
             #field_name: #field_ty,
         });
 
+        let field_docs = field_docs_line(field);
+        if field_docs.is_empty() {
+            arg_docs.push(format!(" - `{}`", field_name));
+        } else {
+            arg_docs.push(format!(" - `{}` - {}", field_name, field_docs));
+        }
+
         constructor_values.extend(quote! {
+            #(#cfg)*
             #field_name,
         });
-    });
+    }
+
+    let docs = match attrs.doc {
+        Some(doc) => quote! { #[doc = #doc] },
+        None => {
+            let summary = format!(" Creates a new `{}`", input.ident);
+
+            if arg_docs.is_empty() {
+                quote! { #[doc = #summary] }
+            } else {
+                quote! {
+                    #[doc = #summary]
+                    #[doc = ""]
+                    #[doc = " # Arguments"]
+                    #(#[doc = #arg_docs])*
+                }
+            }
+        }
+    };
+
+    // The constructor initializes deprecated fields too
+    let allow_deprecated = input
+        .fields
+        .iter()
+        .any(|field| has_attribute(field, "deprecated"))
+        .then(|| quote! { #[allow(deprecated)] });
+    let method_attrs = &attrs.attrs;
+    let constness = attrs.is_const.then(|| quote! { const });
 
     quote! {
-        #constructor_visibility fn #constructor_name(#constructor_args) -> Self {
+        #docs
+        #allow_deprecated
+        #(#[#method_attrs])*
+        #constructor_visibility #constness fn #constructor_name(#constructor_args) -> Self {
             Self {#constructor_values}
         }
     }
@@ -2,7 +2,7 @@
 //! This module contains the `ConstructorStructAttrs` struct which represents the args of a constructor attribute
 //!
 
-use syn::Token;
+use syn::{Meta, Token};
 
 use crate::{
     constructor::{constructor_arg::ConstructorArg, constructor_visibility::ConstructorVisibility},
@@ -15,6 +15,12 @@ pub struct ConstructorStructAttrs {
     pub name: String,
     /// Visibility
     pub visibility: ConstructorVisibility,
+    /// Docs, replace the generated ones
+    pub doc: Option<String>,
+    /// Attributes added to the constructor, e.g. `must_use`
+    pub attrs: Vec<Meta>,
+    /// Whether to generate `const fn`
+    pub is_const: bool,
 }
 
 impl NewFromArgs<ConstructorArg> for ConstructorStructAttrs {
@@ -23,14 +29,27 @@ impl NewFromArgs<ConstructorArg> for ConstructorStructAttrs {
         let mut name = String::from("new");
         // Constructor's default visibility is `pub`
         let mut visibility = ConstructorVisibility::Pub;
+        let mut doc = None;
+        let mut attrs = Vec::new();
+        let mut is_const = false;
 
         for value in args {
             match value {
                 ConstructorArg::Name(n) => name = n,
                 ConstructorArg::Visibility(vis) => visibility = vis,
+                ConstructorArg::Doc(d) => doc = Some(d),
+                ConstructorArg::Attrs(a) => attrs.extend(a),
+                ConstructorArg::Inline(i) => attrs.push(*i),
+                ConstructorArg::Const => is_const = true,
             }
         }
 
-        Self { name, visibility }
+        Self {
+            name,
+            visibility,
+            doc,
+            attrs,
+            is_const,
+        }
     }
 }
@@ -5,4 +5,3 @@ mod constructor_visibility;
 
 pub use constructor_arg::*;
 pub use constructor_struct_attrs::*;
-pub use constructor_visibility::*;
@@ -0,0 +1,78 @@
+//! # debug_arg
+//! This module contains the `DebugArg` enum which represents a single argument of a debug attribute
+//!
+
+use syn::{
+    Error, Ident, MetaNameValue, Path, Result,
+    parse::{Parse, ParseStream},
+    spanned::Spanned,
+};
+
+use crate::{accessors::AccessorArg, helpers::unknown_arg_error, selection::SelectionArg};
+
+/// Names of all debug arguments, listed by the unknown argument error
+const ARG_NAMES: &[&str] = &[
+    "includes",
+    "excludes",
+    "types",
+    "exclude_types",
+    "groups",
+    "exclude_groups",
+    "skip",
+    "with",
+];
+
+/// Enum DebugArg represents a single argument of a debug attribute
+pub enum DebugArg {
+    /// Fields to include or exclude, e.g. `includes = [...]` or `types = [...]`
+    Selection(SelectionArg),
+    /// Omit the field
+    Skip,
+    /// Format the field with a custom function
+    With(Path),
+}
+
+impl DebugArg {
+    /// If the parsed value is MetaNameValue, this means its the construction like: name = value.
+    /// This method parses this construction into an `Arg`
+    pub fn nv_to_arg(nv: &MetaNameValue) -> Result<Self> {
+        let ident = nv
+            .path
+            .get_ident()
+            .ok_or_else(|| Error::new(nv.path.span(), "Wrong name. Expected identifier"))?;
+
+        match ident.to_string().as_str() {
+            "with" => Ok(DebugArg::With(AccessorArg::expr_to_path(&nv.value)?)),
+            _ => Err(unknown_arg_error(ident, ARG_NAMES)),
+        }
+    }
+}
+
+impl Parse for DebugArg {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if SelectionArg::peek(input) {
+            return Ok(DebugArg::Selection(input.parse()?));
+        }
+
+        let ident = input.fork().parse::<Ident>().ok();
+
+        let arg = match ident {
+            Some(ident) if ident == "skip" && !input.peek2(syn::Token![=]) => {
+                input.parse::<Ident>()?;
+                DebugArg::Skip
+            }
+            Some(ident) if !input.peek2(syn::Token![=]) => {
+                return Err(unknown_arg_error(&ident, ARG_NAMES));
+            }
+            _ => {
+                if let Ok(nv) = input.parse::<MetaNameValue>() {
+                    DebugArg::nv_to_arg(&nv)?
+                } else {
+                    return Result::Err(Error::new(input.span(), "Could not parse arg"));
+                }
+            }
+        };
+
+        Ok(arg)
+    }
+}
@@ -0,0 +1,33 @@
+//! # debug_field_attrs
+//! This module contains the `DebugFieldAttrs` struct which represents the args of a field-level debug attribute
+//!
+
+use syn::{Path, Token, punctuated::Punctuated};
+
+use crate::{debug::DebugArg, new_from_args::NewFromArgs};
+
+/// Struct `DebugFieldAttrs` represents the args of a field-level debug attribute
+#[derive(Default)]
+pub struct DebugFieldAttrs {
+    /// Omit the field
+    pub skip: bool,
+    /// Custom formatting function
+    pub with: Option<Path>,
+}
+
+impl NewFromArgs<DebugArg> for DebugFieldAttrs {
+    fn new(args: Punctuated<DebugArg, Token![,]>) -> Self {
+        let mut skip = false;
+        let mut with = None;
+
+        for value in args {
+            match value {
+                DebugArg::Skip => skip = true,
+                DebugArg::With(w) => with = Some(w),
+                _ => {}
+            }
+        }
+
+        Self { skip, with }
+    }
+}
@@ -0,0 +1,109 @@
+//! # debug_processor
+//! This module contains functions that generate the `Debug` impl
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::ItemStruct;
+
+use crate::{
+    attribute_parser::load_args_from_attribute,
+    debug::{DebugArg, DebugFieldAttrs, DebugStructAttrs, Redact},
+    helpers::{fill_includes_if_empty, should_field_be_added},
+};
+
+/// Generates `Debug` impl
+pub fn process_debug(mut attrs: DebugStructAttrs, input: &ItemStruct) -> TokenStream {
+    fill_includes_if_empty(&mut attrs.selection.includes, &input.fields);
+
+    let struct_name = &input.ident;
+    let name = struct_name.to_string();
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut fields = TokenStream::new();
+    let mut non_exhaustive = false;
+    let mut uses_with = false;
+
+    for field in input.fields.iter() {
+        let field_name = field.ident.clone().expect("Error, expected named field");
+        let key = field_name.to_string();
+
+        let mut field_attrs = DebugFieldAttrs::default();
+        let mut redact = None;
+
+        for attribute in &field.attrs {
+            if attribute.path().is_ident("debug") {
+                match load_args_from_attribute::<DebugFieldAttrs, DebugArg>(attribute) {
+                    Ok(a) => field_attrs = a,
+                    Err(e) => return e.to_compile_error(),
+                }
+            } else if attribute.path().is_ident("redact") {
+                match Redact::from_attribute(attribute) {
+                    Ok(r) => redact = Some(r),
+                    Err(e) => return e.to_compile_error(),
+                }
+            }
+        }
+
+        if field_attrs.skip || !should_field_be_added(&attrs, field) {
+            non_exhaustive = true;
+            continue;
+        }
+
+        let value = match (redact, field_attrs.with) {
+            (Some(Redact::Mask), _) => quote! { &"***" },
+            (Some(Redact::Hash), _) => quote! {
+                &{
+                    let mut hasher = ::std::collections::hash_map::DefaultHasher::new();
+                    ::std::hash::Hash::hash(&self.#field_name, &mut hasher);
+                    let hash = ::std::hash::Hasher::finish(&hasher);
+                    ::std::format!("{:08x}", hash >> 32)
+                }
+            },
+            (None, Some(with)) => {
+                uses_with = true;
+                quote! { &__TrlDebugWith(&self.#field_name, |value, f| #with(value, f)) }
+            }
+            (None, None) => quote! { &self.#field_name },
+        };
+
+        fields.extend(quote! {
+            .field(#key, #value)
+        });
+    }
+
+    let finish = if non_exhaustive {
+        quote! { finish_non_exhaustive }
+    } else {
+        quote! { finish }
+    };
+
+    // Formats a field with `#[debug(with = ...)]`
+    let debug_with = if uses_with {
+        quote! {
+            struct __TrlDebugWith<'a, T: ?Sized>(
+                &'a T,
+                fn(&T, &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result,
+            );
+
+            impl<T: ?Sized> ::std::fmt::Debug for __TrlDebugWith<'_, T> {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    (self.1)(self.0, f)
+                }
+            }
+        }
+    } else {
+        TokenStream::new()
+    };
+
+    quote! {
+        impl #impl_generics ::std::fmt::Debug for #struct_name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                #debug_with
+
+                f.debug_struct(#name)
+                    #fields
+                    .#finish()
+            }
+        }
+    }
+}
@@ -0,0 +1,41 @@
+//! # debug_struct_attrs
+//! This module contains the `DebugStructAttrs` struct which represents the args of a struct-level debug attribute
+//!
+
+use syn::{Token, punctuated::Punctuated};
+
+use crate::{
+    debug::DebugArg, field_selection::FieldSelection, new_from_args::NewFromArgs,
+    selection::SelectionAttrs,
+};
+
+/// Struct `DebugStructAttrs` represents the args of a struct-level debug attribute
+pub struct DebugStructAttrs {
+    /// Fields to include and exclude
+    pub selection: SelectionAttrs,
+}
+
+impl NewFromArgs<DebugArg> for DebugStructAttrs {
+    fn new(args: Punctuated<DebugArg, Token![,]>) -> Self {
+        let mut selection = SelectionAttrs::default();
+
+        for value in args {
+            if let DebugArg::Selection(s) = value {
+                selection.add(s);
+            }
+        }
+
+        Self { selection }
+    }
+}
+
+impl FieldSelection for DebugStructAttrs {
+    fn selection(&self) -> &SelectionAttrs {
+        &self.selection
+    }
+
+    // Public fields are printed too, like with `#[derive(Debug)]`
+    fn include_pub(&self) -> bool {
+        true
+    }
+}
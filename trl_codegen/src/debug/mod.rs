@@ -0,0 +1,10 @@
+mod debug_arg;
+mod debug_field_attrs;
+pub mod debug_processor;
+mod debug_struct_attrs;
+mod redact;
+
+pub use debug_arg::*;
+pub use debug_field_attrs::*;
+pub use debug_struct_attrs::*;
+pub use redact::*;
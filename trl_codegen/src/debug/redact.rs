@@ -0,0 +1,37 @@
+//! # redact
+//! This module contains the `Redact` enum which represents how a `#[redact]` field is printed
+//!
+
+use syn::{Attribute, Error, Ident, Meta, Result};
+
+/// Represents how a `#[redact]` field is printed
+pub enum Redact {
+    /// #[redact]
+    /// The field is printed as `"***"`
+    Mask,
+    /// #[redact(hash)]
+    /// The field is printed as a hash prefix, so equal values can be matched in logs
+    Hash,
+}
+
+impl Redact {
+    /// Creates `Redact` from the `#[redact]` attribute
+    pub fn from_attribute(attribute: &Attribute) -> Result<Self> {
+        match &attribute.meta {
+            Meta::Path(_) => Ok(Redact::Mask),
+            Meta::List(_) => {
+                let mode = attribute.parse_args::<Ident>()?;
+
+                if mode == "hash" {
+                    Ok(Redact::Hash)
+                } else {
+                    Err(Error::new(mode.span(), "expected `hash`"))
+                }
+            }
+            Meta::NameValue(nv) => Err(Error::new_spanned(
+                nv,
+                "expected `#[redact]` or `#[redact(hash)]`",
+            )),
+        }
+    }
+}
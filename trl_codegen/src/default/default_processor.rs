@@ -0,0 +1,43 @@
+//! # default_processor
+//! This module contains functions that generate the `Default` impl from per-field default expressions
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Field, ItemStruct};
+
+use crate::trl_attr::TrlFieldAttrs;
+
+/// Returns the default expression of a field marked with `#[trl(default)]` or `#[trl(default = expr)]`
+pub fn field_default(field: &Field) -> Option<TokenStream> {
+    Some(match TrlFieldAttrs::from_field(field).default? {
+        Some(expr) => quote! { #expr },
+        None => quote! { ::std::default::Default::default() },
+    })
+}
+
+/// Generates `Default` impl
+pub fn process_default(input: &ItemStruct) -> TokenStream {
+    let struct_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut field_values = TokenStream::new();
+
+    for field in input.fields.iter() {
+        let field_name = field.ident.clone().expect("Error, expected named field");
+
+        let value =
+            field_default(field).unwrap_or_else(|| quote! { ::std::default::Default::default() });
+
+        field_values.extend(quote! {
+            #field_name: #value,
+        });
+    }
+
+    quote! {
+        impl #impl_generics ::std::default::Default for #struct_name #ty_generics #where_clause {
+            fn default() -> Self {
+                Self { #field_values }
+            }
+        }
+    }
+}
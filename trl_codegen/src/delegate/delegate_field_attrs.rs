@@ -0,0 +1,21 @@
+//! # delegate_field_attrs
+//! This module contains the `DelegateFieldAttrs` struct which represents the methods of a delegate attribute
+//!
+
+use syn::{Token, punctuated::Punctuated};
+
+use crate::{delegate::DelegateMethod, new_from_args::NewFromArgs};
+
+/// Struct `DelegateFieldAttrs` represents the methods of a delegate attribute
+pub struct DelegateFieldAttrs {
+    /// Methods to forward to the field
+    pub methods: Vec<DelegateMethod>,
+}
+
+impl NewFromArgs<DelegateMethod> for DelegateFieldAttrs {
+    fn new(args: Punctuated<DelegateMethod, Token![,]>) -> Self {
+        Self {
+            methods: args.into_iter().collect(),
+        }
+    }
+}
@@ -0,0 +1,41 @@
+//! # delegate_method
+//! This module contains the `DelegateMethod` struct which represents a single method of a delegate attribute
+//!
+
+use syn::{
+    FnArg, Ident, ReturnType, Token, parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+};
+
+/// Represents a single method of a delegate attribute, e.g. `len` or `get(&self, k: &K) -> Option<&V>`
+pub struct DelegateMethod {
+    /// Method name
+    pub name: Ident,
+    /// Method arguments including the receiver, `None` if only the name is specified
+    pub inputs: Option<Punctuated<FnArg, Token![,]>>,
+    /// Method return type
+    pub output: ReturnType,
+}
+
+impl Parse for DelegateMethod {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse::<Ident>()?;
+
+        let inputs = if input.peek(syn::token::Paren) {
+            let content;
+            parenthesized!(content in input);
+            Some(Punctuated::<FnArg, Token![,]>::parse_terminated(&content)?)
+        } else {
+            None
+        };
+
+        let output = input.parse::<ReturnType>()?;
+
+        Ok(DelegateMethod {
+            name,
+            inputs,
+            output,
+        })
+    }
+}
@@ -0,0 +1,113 @@
+//! # delegate_processor
+//! This module contains functions that generate methods forwarded to a field and `Deref` impls
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Error, Field, FnArg, ItemStruct, Pat, Result};
+
+use crate::delegate::{DelegateFieldAttrs, DelegateMethod};
+
+/// Generates methods forwarded to the field
+pub fn process_delegate(attrs: DelegateFieldAttrs, field: &Field) -> TokenStream {
+    let field_name = field.ident.clone().expect("Error, expected named field");
+
+    let mut result = TokenStream::new();
+
+    for method in attrs.methods {
+        match generate_delegate(&method, &field_name) {
+            Ok(tokens) => result.extend(tokens),
+            Err(e) => result.extend(e.to_compile_error()),
+        }
+    }
+
+    result
+}
+
+/// Generates a single forwarded method
+fn generate_delegate(method: &DelegateMethod, field_name: &syn::Ident) -> Result<TokenStream> {
+    let name = &method.name;
+    let doc = format!("Calls `{}` of the `{}`", name, field_name);
+
+    let Some(inputs) = &method.inputs else {
+        return known_signature(method).map(|signature| {
+            quote! {
+                #[doc = #doc]
+                pub fn #signature {
+                    self.#field_name.#name()
+                }
+            }
+        });
+    };
+
+    if !matches!(inputs.first(), Some(FnArg::Receiver(_))) {
+        return Err(Error::new(
+            name.span(),
+            "The first argument of a delegated method must be `self`, `&self` or `&mut self`",
+        ));
+    }
+
+    let args = inputs
+        .iter()
+        .skip(1)
+        .map(|arg| match arg {
+            FnArg::Typed(typed) => match typed.pat.as_ref() {
+                Pat::Ident(ident) => Ok(ident.ident.clone()),
+                pat => Err(Error::new_spanned(pat, "Expected an argument name")),
+            },
+            FnArg::Receiver(receiver) => {
+                Err(Error::new_spanned(receiver, "Unexpected `self` argument"))
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let output = &method.output;
+
+    Ok(quote! {
+        #[doc = #doc]
+        pub fn #name(#inputs) #output {
+            self.#field_name.#name(#(#args),*)
+        }
+    })
+}
+
+/// Returns the signature of a well-known method delegated by name only
+fn known_signature(method: &DelegateMethod) -> Result<TokenStream> {
+    let name = &method.name;
+
+    match name.to_string().as_str() {
+        "len" | "capacity" => Ok(quote! { #name(&self) -> usize }),
+        "is_empty" => Ok(quote! { #name(&self) -> bool }),
+        "clear" | "shrink_to_fit" => Ok(quote! { #name(&mut self) }),
+        _ => Err(Error::new(
+            name.span(),
+            format!(
+                "Unknown signature of `{0}`. Specify it explicitly, e.g. `{0}(&self) -> Type`",
+                name
+            ),
+        )),
+    }
+}
+
+/// Generates `Deref` and `DerefMut` impls to the field
+pub fn process_deref(field: &Field, input: &ItemStruct) -> TokenStream {
+    let field_name = field.ident.clone().expect("Error, expected named field");
+    let field_ty = &field.ty;
+    let struct_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics ::std::ops::Deref for #struct_name #ty_generics #where_clause {
+            type Target = #field_ty;
+
+            fn deref(&self) -> &Self::Target {
+                &self.#field_name
+            }
+        }
+
+        impl #impl_generics ::std::ops::DerefMut for #struct_name #ty_generics #where_clause {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.#field_name
+            }
+        }
+    }
+}
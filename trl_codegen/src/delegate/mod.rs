@@ -0,0 +1,6 @@
+mod delegate_field_attrs;
+mod delegate_method;
+pub mod delegate_processor;
+
+pub use delegate_field_attrs::*;
+pub use delegate_method::*;
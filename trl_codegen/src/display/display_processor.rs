@@ -0,0 +1,125 @@
+//! # display_processor
+//! This module contains functions that generate the `Display` impl from a format template
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Attribute, Error, ItemStruct, LitStr, Result};
+
+/// Generates `Display` impl
+pub fn process_display(attribute: &Attribute, input: &ItemStruct) -> TokenStream {
+    let template = match attribute.parse_args::<LitStr>() {
+        Ok(template) => template,
+        Err(e) => {
+            return Error::new(
+                e.span(),
+                "Expected a format string, e.g. #[display(\"{name}\")]",
+            )
+            .to_compile_error();
+        }
+    };
+
+    let names = match template_arguments(&template) {
+        Ok(names) => names,
+        Err(e) => return e.to_compile_error(),
+    };
+
+    let field_names = input
+        .fields
+        .iter()
+        .filter_map(|field| field.ident.as_ref().map(|i| i.to_string()))
+        .collect::<Vec<_>>();
+
+    for name in &names {
+        if !field_names.contains(name) {
+            return Error::new(
+                template.span(),
+                format!(
+                    "Unknown field `{}` in the display template. Available fields: {}",
+                    name,
+                    field_names.join(", ")
+                ),
+            )
+            .to_compile_error();
+        }
+    }
+
+    let struct_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let names = names
+        .iter()
+        .map(|name| format_ident!("{}", name))
+        .collect::<Vec<_>>();
+
+    quote! {
+        impl #impl_generics ::std::fmt::Display for #struct_name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                ::std::write!(f, #template, #(#names = self.#names),*)
+            }
+        }
+    }
+}
+
+/// Returns the unique names used in the template, including the ones of `width$` and `precision$` specs
+fn template_arguments(template: &LitStr) -> Result<Vec<String>> {
+    let value = template.value();
+    let mut chars = value.chars().peekable();
+    let mut names = Vec::new();
+
+    while let Some(c) = chars.next() {
+        if c != '{' {
+            continue;
+        }
+
+        if chars.peek() == Some(&'{') {
+            chars.next();
+            continue;
+        }
+
+        let mut placeholder = String::new();
+        for c in chars.by_ref() {
+            if c == '}' {
+                break;
+            }
+            placeholder.push(c);
+        }
+
+        let (argument, spec) = placeholder
+            .split_once(':')
+            .unwrap_or((placeholder.as_str(), ""));
+        let argument = argument.trim();
+
+        if argument.is_empty() || argument.chars().next().unwrap().is_ascii_digit() {
+            return Err(Error::new(
+                template.span(),
+                "Positional arguments are not supported in the display template, use field names, e.g. {name}",
+            ));
+        }
+
+        names.push(argument.to_string());
+
+        // Named `width$` and `precision$` arguments of the format spec
+        for part in spec.split('$').collect::<Vec<_>>().iter().rev().skip(1) {
+            let name = part
+                .chars()
+                .rev()
+                .take_while(|c| c.is_alphanumeric() || *c == '_')
+                .collect::<String>()
+                .chars()
+                .rev()
+                .collect::<String>();
+
+            if !name.is_empty() && !name.chars().next().unwrap().is_ascii_digit() {
+                names.push(name);
+            }
+        }
+    }
+
+    let mut unique = Vec::new();
+    for name in names {
+        if !unique.contains(&name) {
+            unique.push(name);
+        }
+    }
+
+    Ok(unique)
+}
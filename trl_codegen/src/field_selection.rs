@@ -0,0 +1,311 @@
+//! # field_selection
+//!
+//! This module contains the `FieldSelection` trait which represents a struct-level attribute info
+//! that selects fields with the `SelectionArg` arguments and `pub`
+
+use proc_macro2::TokenStream;
+use quote::ToTokens;
+use syn::{
+    Error, Field, Fields, GenericArgument, Ident, LitStr, Path, PathArguments, Type, Visibility,
+};
+
+use crate::{
+    helpers::{suggestion, unknown_field_error, warning},
+    selection::SelectionAttrs,
+    trl_attr::TrlFieldAttrs,
+};
+
+/// Represents a struct-level attribute info that selects fields
+pub trait FieldSelection {
+    /// Fields to include and exclude
+    fn selection(&self) -> &SelectionAttrs;
+    /// Whether to include public fields or not
+    fn include_pub(&self) -> bool;
+}
+
+/// A single entry of `includes`/`excludes`
+#[derive(Debug, Clone)]
+pub enum FieldPattern {
+    /// Field name, e.g. `id`
+    Name(Ident),
+    /// Glob of field names, e.g. `"*_id"`. `*` matches any sequence of characters, `?` matches one
+    Glob(LitStr),
+    /// Field type, e.g. `Option<_>`. `_` matches any type
+    Type(Type),
+    /// Group of fields, set with `#[trl(group = ...)]`
+    Group(Ident),
+}
+
+impl FieldPattern {
+    /// Checks whether the field is selected by the pattern
+    pub fn matches(&self, field: &Field) -> bool {
+        match self {
+            FieldPattern::Name(name) => field.ident.as_ref() == Some(name),
+            FieldPattern::Glob(glob) => field.ident.as_ref().is_some_and(|ident| {
+                glob_matches(
+                    &glob.value().chars().collect::<Vec<_>>(),
+                    &ident.to_string().chars().collect::<Vec<_>>(),
+                )
+            }),
+            FieldPattern::Type(ty) => type_matches(ty, &field.ty),
+            FieldPattern::Group(group) => TrlFieldAttrs::from_field(field).groups.contains(group),
+        }
+    }
+}
+
+/// Checks the `includes` and `excludes` names against the fields of the struct
+///
+/// Unknown names and patterns matching no field are errors. Names listed in both arguments and public fields included without `pub`
+/// are warnings
+pub fn check_field_selection(
+    attrs: &impl FieldSelection,
+    fields: &Fields,
+) -> syn::Result<TokenStream> {
+    let field_names = fields
+        .iter()
+        .filter_map(|field| field.ident.as_ref().map(ToString::to_string))
+        .collect::<Vec<_>>();
+    let field_names = field_names.iter().map(String::as_str).collect::<Vec<_>>();
+
+    let group_names = fields
+        .iter()
+        .flat_map(|field| TrlFieldAttrs::from_field(field).groups)
+        .map(|group| group.to_string())
+        .collect::<Vec<_>>();
+    let group_names = group_names.iter().map(String::as_str).collect::<Vec<_>>();
+
+    let mut error: Option<Error> = None;
+    let mut warnings = TokenStream::new();
+
+    let selection = attrs.selection();
+
+    for pattern in selection.includes.iter().chain(&selection.excludes) {
+        let unknown = match pattern {
+            FieldPattern::Name(name) if !field_names.contains(&name.to_string().as_str()) => {
+                unknown_field_error(name, &field_names)
+            }
+            FieldPattern::Glob(glob) if !fields.iter().any(|field| pattern.matches(field)) => {
+                Error::new(
+                    glob.span(),
+                    format!("Pattern `{}` matches no field", glob.value()),
+                )
+            }
+            FieldPattern::Type(ty) if !fields.iter().any(|field| pattern.matches(field)) => {
+                Error::new_spanned(
+                    ty,
+                    format!("Type `{}` matches no field", ty.to_token_stream()),
+                )
+            }
+            FieldPattern::Group(group) if !fields.iter().any(|field| pattern.matches(field)) => {
+                Error::new(
+                    group.span(),
+                    format!(
+                        "Group `{group}` matches no field.{}",
+                        suggestion(group, &group_names)
+                    ),
+                )
+            }
+            _ => continue,
+        };
+
+        match &mut error {
+            Some(error) => error.combine(unknown),
+            None => error = Some(unknown),
+        }
+    }
+
+    for name in names(&selection.includes) {
+        let is_pub = fields.iter().any(|field| {
+            field.ident.as_ref() == Some(name) && matches!(field.vis, Visibility::Public(..))
+        });
+
+        if is_pub && !attrs.include_pub() {
+            warnings.extend(warning(
+                name.span(),
+                &format!("Field `{name}` is public and is ignored without the `pub` argument"),
+            ));
+        }
+    }
+
+    for name in names(&selection.excludes) {
+        if names(&selection.includes).any(|included| included == name) {
+            warnings.extend(warning(
+                name.span(),
+                &format!("Field `{name}` is listed in both `includes` and `excludes`"),
+            ));
+        }
+    }
+
+    match error {
+        Some(error) => Err(error),
+        None => Ok(warnings),
+    }
+}
+
+/// Returns the field names listed in the patterns
+fn names(patterns: &[FieldPattern]) -> impl Iterator<Item = &Ident> {
+    patterns.iter().filter_map(|pattern| match pattern {
+        FieldPattern::Name(name) => Some(name),
+        _ => None,
+    })
+}
+
+/// Matches the name against the glob
+fn glob_matches(glob: &[char], name: &[char]) -> bool {
+    match glob.split_first() {
+        None => name.is_empty(),
+        Some(('*', rest)) => (0..=name.len()).any(|i| glob_matches(rest, &name[i..])),
+        Some(('?', rest)) => !name.is_empty() && glob_matches(rest, &name[1..]),
+        Some((c, rest)) => name.first() == Some(c) && glob_matches(rest, &name[1..]),
+    }
+}
+
+/// Matches the type against the pattern. `_` in the pattern matches any type
+fn type_matches(pattern: &Type, ty: &Type) -> bool {
+    match (pattern, ty) {
+        (Type::Infer(_), _) => true,
+        (Type::Group(group), _) => type_matches(&group.elem, ty),
+        (_, Type::Group(group)) => type_matches(pattern, &group.elem),
+        (Type::Paren(paren), _) => type_matches(&paren.elem, ty),
+        (_, Type::Paren(paren)) => type_matches(pattern, &paren.elem),
+        (Type::Path(pattern), Type::Path(ty)) if pattern.qself.is_none() && ty.qself.is_none() => {
+            path_matches(&pattern.path, &ty.path)
+        }
+        (Type::Reference(pattern), Type::Reference(ty)) => {
+            pattern.mutability.is_some() == ty.mutability.is_some()
+                && type_matches(&pattern.elem, &ty.elem)
+        }
+        (Type::Slice(pattern), Type::Slice(ty)) => type_matches(&pattern.elem, &ty.elem),
+        (Type::Array(pattern), Type::Array(ty)) => {
+            type_matches(&pattern.elem, &ty.elem)
+                && pattern.len.to_token_stream().to_string() == ty.len.to_token_stream().to_string()
+        }
+        (Type::Tuple(pattern), Type::Tuple(ty)) => {
+            pattern.elems.len() == ty.elems.len()
+                && pattern
+                    .elems
+                    .iter()
+                    .zip(&ty.elems)
+                    .all(|(pattern, ty)| type_matches(pattern, ty))
+        }
+        _ => pattern.to_token_stream().to_string() == ty.to_token_stream().to_string(),
+    }
+}
+
+/// Matches the path against the pattern, the pattern may omit leading segments,
+/// e.g. `String` matches `std::string::String`
+fn path_matches(pattern: &Path, path: &Path) -> bool {
+    let skip = match path.segments.len().checked_sub(pattern.segments.len()) {
+        Some(skip) => skip,
+        None => return false,
+    };
+
+    pattern
+        .segments
+        .iter()
+        .zip(path.segments.iter().skip(skip))
+        .all(|(pattern, segment)| {
+            pattern.ident == segment.ident
+                && match (&pattern.arguments, &segment.arguments) {
+                    (
+                        PathArguments::AngleBracketed(pattern),
+                        PathArguments::AngleBracketed(args),
+                    ) => {
+                        pattern.args.len() == args.args.len()
+                            && pattern.args.iter().zip(&args.args).all(|pair| match pair {
+                                (GenericArgument::Type(pattern), GenericArgument::Type(ty)) => {
+                                    type_matches(pattern, ty)
+                                }
+                                (pattern, arg) => {
+                                    pattern.to_token_stream().to_string()
+                                        == arg.to_token_stream().to_string()
+                                }
+                            })
+                    }
+                    (pattern, args) => {
+                        pattern.to_token_stream().to_string() == args.to_token_stream().to_string()
+                    }
+                }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::{ItemStruct, parse_quote};
+
+    impl FieldSelection for SelectionAttrs {
+        fn selection(&self) -> &SelectionAttrs {
+            self
+        }
+
+        fn include_pub(&self) -> bool {
+            false
+        }
+    }
+
+    fn fields() -> Fields {
+        let input: ItemStruct = parse_quote! {
+            struct User {
+                user_id: u64,
+                name: String,
+                email: Option<String>,
+                pub role: std::string::String,
+            }
+        };
+
+        input.fields
+    }
+
+    #[test]
+    fn check_field_selection_test() {
+        let selection = SelectionAttrs {
+            includes: vec![
+                FieldPattern::Name(parse_quote!(nmae)),
+                FieldPattern::Glob(parse_quote!("*_at")),
+            ],
+            excludes: vec![FieldPattern::Type(parse_quote!(Vec<_>))],
+        };
+
+        let error = check_field_selection(&selection, &fields()).unwrap_err();
+        let messages = error.into_iter().map(|e| e.to_string()).collect::<Vec<_>>();
+
+        assert!(messages.len() == 3);
+        assert!(messages[0].contains("`nmae`") && messages[0].contains("Did you mean `name`?"));
+        assert!(messages[1] == "Pattern `*_at` matches no field");
+        assert!(messages[2] == "Type `Vec < _ >` matches no field");
+
+        let selection = SelectionAttrs {
+            includes: vec![
+                FieldPattern::Name(parse_quote!(name)),
+                FieldPattern::Name(parse_quote!(role)),
+            ],
+            excludes: vec![FieldPattern::Name(parse_quote!(name))],
+        };
+
+        let warnings = check_field_selection(&selection, &fields())
+            .unwrap()
+            .to_string();
+
+        assert!(warnings.contains("Field `role` is public"));
+        assert!(warnings.contains("Field `name` is listed in both"));
+    }
+
+    #[test]
+    fn field_pattern_matches_test() {
+        let fields = fields();
+        let selected = |pattern: FieldPattern| {
+            fields
+                .iter()
+                .filter(|field| pattern.matches(field))
+                .map(|field| field.ident.as_ref().unwrap().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert!(selected(FieldPattern::Glob(parse_quote!("*_id"))) == ["user_id"]);
+        assert!(selected(FieldPattern::Glob(parse_quote!("?ame"))) == ["name"]);
+        assert!(selected(FieldPattern::Type(parse_quote!(String))) == ["name", "role"]);
+        assert!(selected(FieldPattern::Type(parse_quote!(Option<_>))) == ["email"]);
+        assert!(selected(FieldPattern::Type(parse_quote!(_))).len() == 4);
+    }
+}
@@ -2,40 +2,52 @@
 //!
 //! This module contains some helper functions
 
-use proc_macro2::TokenStream;
-use quote::quote;
-use syn::{Field, Fields, Ident, Type, Visibility};
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    Attribute, Error, Expr, ExprLit, Field, Fields, Ident, Lit, Meta, Token, Type, Visibility,
+    ext::IdentExt, parse::ParseStream, punctuated::Punctuated,
+};
 
-use crate::accessors::AccessorStructAttrs;
+use crate::{
+    accessors::SetterHooks,
+    field_selection::{FieldPattern, FieldSelection},
+    hook::Hook,
+    interior::Interior,
+};
 
-pub fn fill_includes_if_empty(includes: &mut Vec<String>, fields: &Fields) {
+pub fn fill_includes_if_empty(includes: &mut Vec<FieldPattern>, fields: &Fields) {
     if !includes.is_empty() {
         return;
     }
 
     for field in fields.iter() {
-        includes.push(field.ident.clone().unwrap().to_string());
+        includes.push(FieldPattern::Name(field.ident.clone().unwrap()));
     }
 }
 
-pub fn should_add_pub(attrs: &AccessorStructAttrs, field: &Field) -> bool {
-    if !attrs.include_pub {
-        if let Visibility::Public(..) = field.vis {
-            return false;
-        }
+pub fn should_add_pub(attrs: &impl FieldSelection, field: &Field) -> bool {
+    if !attrs.include_pub()
+        && let Visibility::Public(..) = field.vis
+    {
+        return false;
     };
 
     true
 }
 
 /// Checks whether the field should be included based on the `includes` and `excludes` arguments
-pub fn should_include(attrs: &AccessorStructAttrs, field: &Field) -> bool {
-    if !attrs
+pub fn should_include(attrs: &impl FieldSelection, field: &Field) -> bool {
+    let selection = attrs.selection();
+
+    if !selection
         .includes
-        .contains(&field.ident.clone().unwrap().to_string())
-        || attrs
+        .iter()
+        .any(|pattern| pattern.matches(field))
+        || selection
             .excludes
-            .contains(&field.ident.clone().unwrap().to_string())
+            .iter()
+            .any(|pattern| pattern.matches(field))
     {
         return false;
     }
@@ -43,9 +55,103 @@ pub fn should_include(attrs: &AccessorStructAttrs, field: &Field) -> bool {
     true
 }
 
-/// Checks whether the field should be included based on the `StructAttrs` struct
-pub fn should_field_be_added(attrs: &AccessorStructAttrs, field: &Field) -> bool {
-    should_add_pub(&attrs, &field) && should_include(&attrs, &field)
+/// Checks whether the field should be included based on the `FieldSelection` args
+pub fn should_field_be_added(attrs: &impl FieldSelection, field: &Field) -> bool {
+    should_add_pub(attrs, field) && should_include(attrs, field)
+}
+
+/// Generates `#[doc]` attributes of a method: the summary followed by the field's own docs
+///
+/// `doc` replaces both if specified
+pub fn method_docs(summary: &str, field: &Field, doc: Option<&str>) -> TokenStream {
+    if let Some(doc) = doc {
+        return quote! { #[doc = #doc] };
+    }
+
+    let summary = format!(" {}", summary);
+    let field_docs = field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("doc"))
+        .collect::<Vec<_>>();
+
+    if field_docs.is_empty() {
+        return quote! { #[doc = #summary] };
+    }
+
+    quote! {
+        #[doc = #summary]
+        #[doc = ""]
+        #(#field_docs)*
+    }
+}
+
+/// Returns the field attributes that are copied onto methods generated for the field:
+/// `cfg`, `cfg_attr` and `deprecated`
+///
+/// `cfg_attr` is copied only if it wraps attributes that apply to methods,
+/// so e.g. `cfg_attr(feature = "serde", serde(skip))` is skipped
+pub fn propagated_attributes(field: &Field) -> Vec<&Attribute> {
+    field
+        .attrs
+        .iter()
+        .filter(|attr| {
+            attr.path().is_ident("cfg")
+                || attr.path().is_ident("deprecated")
+                || (attr.path().is_ident("cfg_attr") && is_method_cfg_attr(attr))
+        })
+        .collect()
+}
+
+/// Checks whether all attributes wrapped by `cfg_attr(predicate, ...)` apply to methods
+fn is_method_cfg_attr(attr: &Attribute) -> bool {
+    const METHOD_ATTRIBUTES: [&str; 7] = [
+        "cfg",
+        "deprecated",
+        "doc",
+        "allow",
+        "warn",
+        "deny",
+        "expect",
+    ];
+
+    let Ok(args) = attr.parse_args_with(Punctuated::<Meta, Token![,]>::parse_terminated) else {
+        return false;
+    };
+
+    args.iter().skip(1).all(|meta| {
+        METHOD_ATTRIBUTES
+            .iter()
+            .any(|name| meta.path().is_ident(name))
+    })
+}
+
+/// Returns the `cfg` attributes of the field
+pub fn cfg_attributes(field: &Field) -> Vec<&Attribute> {
+    field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("cfg"))
+        .collect()
+}
+
+/// Returns the field's own docs joined into a single line
+pub fn field_docs_line(field: &Field) -> String {
+    field
+        .attrs
+        .iter()
+        .filter_map(|attr| match &attr.meta {
+            Meta::NameValue(nv) if nv.path.is_ident("doc") => match &nv.value {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(s), ..
+                }) => Some(s.value().trim().to_string()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
 }
 
 /// Generate a single getter `TokenStream`
@@ -54,19 +160,260 @@ pub fn generate_getter(
     modifier: &TokenStream,
     field_name: &Ident,
     ty: &Type,
+    docs: &TokenStream,
+    is_const: bool,
 ) -> TokenStream {
+    let constness = is_const.then(|| quote! { const });
+
     quote! {
-        pub fn #name(#modifier self) -> #modifier #ty {
+        #docs
+        pub #constness fn #name(#modifier self) -> #modifier #ty {
             #modifier self.#field_name
         }
     }
 }
 
 /// Generates a single setter `TokenStream`
-pub fn generate_setter(name: &Ident, field_name: &Ident, ty: &Type) -> TokenStream {
+///
+/// The value is transformed with `map` and checked with `validate` first. If there's a validator,
+/// the setter returns `Result<(), E>` and leaves the field untouched on error.
+/// `mark_dirty` is executed before the assignment, so it can compare the old value with the new one.
+/// `notify` is called after the assignment with references to the old and new values.
+/// Fields wrapped in `interior` are set through the wrapper with `&self`
+pub fn generate_setter(
+    name: &Ident,
+    field_name: &Ident,
+    ty: &Type,
+    hooks: &SetterHooks,
+    interior: Option<&Interior>,
+    docs: &TokenStream,
+) -> TokenStream {
+    let field = field_name.to_string();
+    let mark_dirty = &hooks.mark_dirty;
+
+    let map = hooks.map.as_ref().map(|map| {
+        quote! { let value = #map(value); }
+    });
+
+    let validate = hooks.validate.as_ref().map(|validate| {
+        let call = match validate {
+            Hook::Function(_) => validate.call(quote! { &value }),
+            Hook::Method(_) => validate.call(quote! { #field, &value }),
+        };
+
+        quote! { #call?; }
+    });
+
+    if interior.is_some() && !hooks.mark_dirty.is_empty() {
+        return Error::new(
+            field_name.span(),
+            "Change tracking is not supported for interior mutability wrappers",
+        )
+        .to_compile_error();
+    }
+
+    let (receiver, ty) = match interior {
+        Some(interior) => (quote! { &self }, interior.inner()),
+        None => (quote! { &mut self }, ty),
+    };
+
+    let assign = match interior {
+        Some(interior) => interior.assign(field_name, hooks.notify.as_ref()),
+        None => match &hooks.notify {
+            Some(notify) => {
+                let call = notify.call(quote! { #field, &old, &self.#field_name });
+
+                quote! {
+                    let old = ::std::mem::replace(&mut self.#field_name, value);
+                    #call;
+                }
+            }
+            None => quote! { self.#field_name = value; },
+        },
+    };
+
+    if validate.is_some() {
+        let error = hooks
+            .error
+            .as_ref()
+            .map(|e| quote! { #e })
+            .unwrap_or(quote! { ::std::string::String });
+
+        quote! {
+            #docs
+            pub fn #name(#receiver, value: #ty) -> ::std::result::Result<(), #error> {
+                #map
+                #validate
+                #mark_dirty
+                #assign
+                ::std::result::Result::Ok(())
+            }
+        }
+    } else {
+        quote! {
+            #docs
+            pub fn #name(#receiver, value: #ty) {
+                #map
+                #mark_dirty
+                #assign
+            }
+        }
+    }
+}
+
+/// Checks whether the field has a marker attribute with the specified name, e.g. `#[dirty]`
+pub fn has_attribute(field: &Field, name: &str) -> bool {
+    field.attrs.iter().any(|a| a.path().is_ident(name))
+}
+
+/// Converts a `snake_case` field name into a `PascalCase` identifier
+pub fn to_pascal_case(field_name: &Ident) -> Ident {
+    let name = field_name
+        .to_string()
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                None => String::new(),
+            }
+        })
+        .collect::<String>();
+
+    format_ident!("{}", name)
+}
+
+/// Creates the error of an unknown attribute argument.
+/// The error lists the valid names and suggests the closest one by edit distance
+pub fn unknown_arg_error(name: &Ident, valid: &[&str]) -> Error {
+    Error::new(
+        name.span(),
+        format!(
+            "Unknown arg `{name}`.{} Valid args: {}",
+            suggestion(name, valid),
+            name_list(valid)
+        ),
+    )
+}
+
+/// Checks that the next argument is one of `names`, the args taken by the `attribute`.
+/// An arg of `all`, the args of related attributes, is reported as not taken by the `attribute`,
+/// other args are reported as unknown. `include`/`exclude` are checked as `includes`/`excludes`
+pub fn check_arg_name(
+    input: ParseStream,
+    attribute: &str,
+    names: &[&str],
+    all: &[&str],
+) -> syn::Result<()> {
+    let Ok(ident) = input.fork().call(Ident::parse_any) else {
+        return Ok(());
+    };
+
+    let written = match ident.to_string().as_str() {
+        "mut" => String::from("mut ref"),
+        name => name.to_string(),
+    };
+    let name = match written.as_str() {
+        "include" => "includes",
+        "exclude" => "excludes",
+        name => name,
+    };
+
+    if names.contains(&name) {
+        return Ok(());
+    }
+
+    Err(if all.contains(&name) {
+        misplaced_arg_error(&ident, &written, attribute, names)
+    } else {
+        unknown_arg_error(&ident, names)
+    })
+}
+
+/// Creates the error of a known argument used in an attribute that doesn't take it.
+/// The error lists the args valid in that attribute
+pub fn misplaced_arg_error(name: &Ident, arg: &str, attribute: &str, valid: &[&str]) -> Error {
+    Error::new(
+        name.span(),
+        format!(
+            "`{arg}` is not an argument of `#[{attribute}]`. Valid args: {}",
+            name_list(valid)
+        ),
+    )
+}
+
+/// Creates the error of a name that is not a field of the struct, suggesting the closest field
+pub fn unknown_field_error(name: &Ident, fields: &[&str]) -> Error {
+    Error::new(
+        name.span(),
+        format!(
+            "Unknown field `{name}`.{} Fields: {}",
+            suggestion(name, fields),
+            name_list(fields)
+        ),
+    )
+}
+
+/// Generates a warning pointing at `span`
+///
+/// Proc macros can't emit warnings on stable, so the warning is the use of a deprecated item
+pub fn warning(span: Span, message: &str) -> TokenStream {
+    let item = Ident::new("TrlWarning", span);
+
     quote! {
-        pub fn #name(&mut self, value: #ty) {
-            self.#field_name = value;
+        const _: () = {
+            #[deprecated(note = #message)]
+            struct TrlWarning;
+
+            let _ = #item;
+        };
+    }
+}
+
+/// Returns ``" Did you mean `name`?"`` for the closest valid name, or an empty string
+pub fn suggestion(name: &Ident, valid: &[&str]) -> String {
+    let unknown = name.to_string();
+
+    valid
+        .iter()
+        .map(|v| (edit_distance(&unknown, v), v))
+        .filter(|(distance, v)| {
+            *distance < unknown.len() && *distance <= (v.len().max(unknown.len()) / 3).max(2)
+        })
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, v)| format!(" Did you mean `{v}`?"))
+        .unwrap_or_default()
+}
+
+/// Formats names as a comma separated list
+fn name_list(names: &[&str]) -> String {
+    names
+        .iter()
+        .map(|n| format!("`{n}`"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Levenshtein distance between two strings
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+
+        for (j, cb) in b.iter().enumerate() {
+            let current = row[j + 1];
+            row[j + 1] = if ca == *cb {
+                previous
+            } else {
+                1 + previous.min(current).min(row[j])
+            };
+            previous = current;
         }
     }
+
+    row[b.len()]
 }
@@ -0,0 +1,28 @@
+//! # hook
+//!
+//! This module contains the `Hook` enum which represents a user function called by a generated setter
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::Path;
+
+/// Represents a user function called by a generated setter
+#[derive(Debug, Clone)]
+pub enum Hook {
+    /// Field-level hook, e.g. `#[set(notify = path::to::callback)]`
+    /// Called as `callback(args)`
+    Function(Path),
+    /// Struct-level hook, e.g. `#[setters(notify = method_name)]`
+    /// Called as `self.method_name(args)`
+    Method(Path),
+}
+
+impl Hook {
+    /// Generates the hook call with the specified arguments
+    pub fn call(&self, args: TokenStream) -> TokenStream {
+        match self {
+            Hook::Function(f) => quote! { #f(#args) },
+            Hook::Method(m) => quote! { self.#m(#args) },
+        }
+    }
+}
@@ -0,0 +1,428 @@
+//! # interior
+//!
+//! This module contains the `Interior` enum which represents an interior mutability wrapper of a field,
+//! e.g. `RefCell<T>` or `AtomicU64`, and generates accessors that go through the wrapper
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Error, Field, GenericArgument, Ident, Path, PathArguments, Type, parse_quote};
+
+use crate::{hook::Hook, method_attrs::MethodAttrs, ordering::AtomicOrdering};
+
+/// Interior mutability wrapper of a field type
+#[derive(Debug, Clone)]
+pub enum Interior {
+    /// `Cell<T>`, accessed by copying the value
+    Cell(Type),
+    /// `RefCell<T>`, accessed with `Ref` and `RefMut`
+    RefCell(Type),
+    /// `Mutex<T>`, accessed with `MutexGuard`
+    Mutex(Type),
+    /// `RwLock<T>`, accessed with read and write guards
+    RwLock(Type),
+    /// Atomic type, e.g. `AtomicU64`, accessed with `load`, `store` and other atomic operations
+    Atomic(Type, AtomicOrdering),
+}
+
+impl Interior {
+    /// Recognises the wrapper by the type path, e.g. `RefCell<T>` or `std::cell::RefCell<T>`
+    ///
+    /// A bare name is assumed to be imported from std, a qualified path must be a std one,
+    /// so `parking_lot::Mutex<T>` is a regular field. `ordering` is used by atomic accessors
+    pub fn from_type(ty: &Type, ordering: AtomicOrdering) -> Option<Interior> {
+        let Type::Path(path) = ty else {
+            return None;
+        };
+
+        if path.qself.is_some() {
+            return None;
+        }
+
+        let segment = path.path.segments.last()?;
+
+        if let Some(primitive) = atomic_primitive(&segment.ident) {
+            return is_std_path(&path.path, &["sync", "atomic"])
+                .then_some(Interior::Atomic(primitive, ordering));
+        }
+
+        let PathArguments::AngleBracketed(args) = &segment.arguments else {
+            return None;
+        };
+
+        let mut types = args.args.iter().filter_map(|arg| match arg {
+            GenericArgument::Type(ty) => Some(ty.clone()),
+            _ => None,
+        });
+
+        let inner = types.next()?;
+
+        if types.next().is_some() {
+            return None;
+        }
+
+        match segment.ident.to_string().as_str() {
+            "Cell" if is_std_path(&path.path, &["cell"]) => Some(Interior::Cell(inner)),
+            "RefCell" if is_std_path(&path.path, &["cell"]) => Some(Interior::RefCell(inner)),
+            "Mutex" if is_std_path(&path.path, &["sync"]) => Some(Interior::Mutex(inner)),
+            "RwLock" if is_std_path(&path.path, &["sync"]) => Some(Interior::RwLock(inner)),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the wrapped value can be returned by the getters
+    ///
+    /// `Cell::get` requires `Copy`, so `Cell` fields of other types get the regular getter
+    pub fn has_getters(&self) -> bool {
+        match self {
+            Interior::Cell(ty) => is_copy(ty),
+            _ => true,
+        }
+    }
+
+    /// Returns the wrapped type
+    pub fn inner(&self) -> &Type {
+        match self {
+            Interior::Cell(ty)
+            | Interior::RefCell(ty)
+            | Interior::Mutex(ty)
+            | Interior::RwLock(ty)
+            | Interior::Atomic(ty, _) => ty,
+        }
+    }
+
+    /// Generates getters of the wrapped value
+    ///
+    /// `name` is the field name or the `name` argument, `prefix` is prepended to every generated method
+    /// and `mut_suffix` is appended to the `RefCell` mutable getter.
+    /// `Cell` getters are named `get_{name}` unless a prefix is specified
+    pub fn getters(
+        &self,
+        prefix: &str,
+        name: &Ident,
+        mut_suffix: &str,
+        field: &Field,
+        method: &MethodAttrs,
+    ) -> TokenStream {
+        let ty = self.inner();
+        let field_name = field.ident.as_ref().expect("Error, expected named field");
+        let docs = |summary: &str| method.tokens(summary, field);
+
+        match self {
+            Interior::Cell(_) => {
+                let prefix = if prefix.is_empty() { "get_" } else { prefix };
+                let getter_name = format_ident!("{}{}", prefix, name);
+
+                let docs = docs(&format!("Returns a copy of the `{}`", field_name));
+
+                quote! {
+                    #docs
+                    pub fn #getter_name(&self) -> #ty {
+                        self.#field_name.get()
+                    }
+                }
+            }
+            Interior::RefCell(_) => {
+                let getter_name = format_ident!("{}{}", prefix, name);
+                let getter_mut_name = format_ident!("{}{}{}", prefix, name, mut_suffix);
+
+                let docs_ref = docs(&format!("Borrows the `{}`", field_name));
+                let docs_mut = docs(&format!("Mutably borrows the `{}`", field_name));
+
+                quote! {
+                    #docs_ref
+                    pub fn #getter_name(&self) -> ::std::cell::Ref<'_, #ty> {
+                        self.#field_name.borrow()
+                    }
+
+                    #docs_mut
+                    pub fn #getter_mut_name(&self) -> ::std::cell::RefMut<'_, #ty> {
+                        self.#field_name.borrow_mut()
+                    }
+                }
+            }
+            Interior::Mutex(_) => {
+                let getter_name = format_ident!("{}lock_{}", prefix, name);
+                let lock = self.write(field_name);
+
+                let docs = docs(&format!("Locks the `{}`", field_name));
+
+                quote! {
+                    #docs
+                    pub fn #getter_name(&self) -> ::std::sync::MutexGuard<'_, #ty> {
+                        #lock
+                    }
+                }
+            }
+            Interior::RwLock(_) => {
+                let read_name = format_ident!("{}read_{}", prefix, name);
+                let write_name = format_ident!("{}write_{}", prefix, name);
+                let read = self.read(field_name);
+                let write = self.write(field_name);
+
+                let docs_read = docs(&format!("Locks the `{}` for reading", field_name));
+                let docs_write = docs(&format!("Locks the `{}` for writing", field_name));
+
+                quote! {
+                    #docs_read
+                    pub fn #read_name(&self) -> ::std::sync::RwLockReadGuard<'_, #ty> {
+                        #read
+                    }
+
+                    #docs_write
+                    pub fn #write_name(&self) -> ::std::sync::RwLockWriteGuard<'_, #ty> {
+                        #write
+                    }
+                }
+            }
+            Interior::Atomic(_, _) => {
+                let getter_name = format_ident!("{}{}", prefix, name);
+                let read = self.read(field_name);
+                let docs = docs(&format!("Loads the `{}`", field_name));
+
+                quote! {
+                    #docs
+                    pub fn #getter_name(&self) -> #ty {
+                        #read
+                    }
+                }
+            }
+        }
+    }
+
+    /// Generates methods that modify the wrapped value besides the setter,
+    /// e.g. `swap_x` and `compare_exchange_x` of atomics
+    pub fn modifiers(&self, name: &Ident, field: &Field, method: &MethodAttrs) -> TokenStream {
+        let Interior::Atomic(ty, ordering) = self else {
+            return TokenStream::new();
+        };
+
+        let field_name = field.ident.as_ref().expect("Error, expected named field");
+        let docs = |summary: &str| method.tokens(summary, field);
+        let docs_swap = docs(&format!(
+            "Stores the value into the `{}`, returning the previous value",
+            field_name
+        ));
+        let docs_compare_exchange = docs(&format!(
+            "Stores `new` into the `{}` if it's equal to `current`, returning the previous value",
+            field_name
+        ));
+
+        let swap_name = format_ident!("swap_{}", name);
+        let compare_exchange_name = format_ident!("compare_exchange_{}", name);
+        let rmw = ordering.rmw();
+        let load = ordering.load();
+
+        let mut result = quote! {
+            #docs_swap
+            pub fn #swap_name(&self, value: #ty) -> #ty {
+                self.#field_name.swap(value, #rmw)
+            }
+
+            #docs_compare_exchange
+            pub fn #compare_exchange_name(
+                &self,
+                current: #ty,
+                new: #ty,
+            ) -> ::std::result::Result<#ty, #ty> {
+                self.#field_name.compare_exchange(current, new, #rmw, #load)
+            }
+        };
+
+        if *ty != parse_quote!(bool) {
+            let fetch_add_name = format_ident!("fetch_add_{}", name);
+            let docs_fetch_add = docs(&format!(
+                "Adds the value to the `{}`, returning the previous value",
+                field_name
+            ));
+
+            result.extend(quote! {
+                #docs_fetch_add
+                pub fn #fetch_add_name(&self, value: #ty) -> #ty {
+                    self.#field_name.fetch_add(value, #rmw)
+                }
+            });
+        }
+
+        result
+    }
+
+    /// Generates setter statements that store `value` in the wrapper
+    ///
+    /// The write access is released before `notify` is called with the old value and the new one borrowed again
+    ///
+    /// `Cell` can't lend its value, so `notify` on a `Cell` is supported only for `Copy` types
+    pub fn assign(&self, field_name: &Ident, notify: Option<&Hook>) -> TokenStream {
+        let field = field_name.to_string();
+
+        if let Interior::Cell(ty) = self {
+            return match notify {
+                Some(_) if !is_copy(ty) => Error::new(
+                    field_name.span(),
+                    "`notify` on a `Cell` requires a `Copy` type, use `RefCell` for other types",
+                )
+                .to_compile_error(),
+                Some(notify) => {
+                    let call = notify.call(quote! { #field, &old, &value });
+
+                    quote! {
+                        let old = self.#field_name.replace(value);
+                        #call;
+                    }
+                }
+                None => quote! { self.#field_name.set(value); },
+            };
+        }
+
+        if let Interior::Atomic(_, ordering) = self {
+            return match notify {
+                Some(notify) => {
+                    let rmw = ordering.rmw();
+                    let call = notify.call(quote! { #field, &old, &value });
+
+                    quote! {
+                        let old = self.#field_name.swap(value, #rmw);
+                        #call;
+                    }
+                }
+                None => {
+                    let store = ordering.store();
+
+                    quote! { self.#field_name.store(value, #store); }
+                }
+            };
+        }
+
+        let write = self.write(field_name);
+
+        match notify {
+            Some(notify) => {
+                let read = self.read(field_name);
+                let call = notify.call(quote! { #field, &old, &*#read });
+
+                quote! {
+                    let old = ::std::mem::replace(&mut *#write, value);
+                    #call;
+                }
+            }
+            None => quote! { *#write = value; },
+        }
+    }
+
+    /// Generates the expression that gives shared access to the wrapped value
+    ///
+    /// Poisoned locks are recovered, since a setter overwrites the value anyway
+    fn read(&self, field_name: &Ident) -> TokenStream {
+        match self {
+            Interior::Cell(_) => quote! { self.#field_name.get() },
+            Interior::RefCell(_) => quote! { self.#field_name.borrow() },
+            Interior::Mutex(_) => self.write(field_name),
+            Interior::RwLock(_) => quote! {
+                self.#field_name.read().unwrap_or_else(::std::sync::PoisonError::into_inner)
+            },
+            Interior::Atomic(_, ordering) => {
+                let load = ordering.load();
+
+                quote! { self.#field_name.load(#load) }
+            }
+        }
+    }
+
+    /// Generates the expression that gives mutable access to the wrapped value
+    fn write(&self, field_name: &Ident) -> TokenStream {
+        match self {
+            Interior::Cell(_) | Interior::Atomic(_, _) => {
+                unreachable!("`Cell` and atomics are accessed by value")
+            }
+            Interior::RefCell(_) => quote! { self.#field_name.borrow_mut() },
+            Interior::Mutex(_) => quote! {
+                self.#field_name.lock().unwrap_or_else(::std::sync::PoisonError::into_inner)
+            },
+            Interior::RwLock(_) => quote! {
+                self.#field_name.write().unwrap_or_else(::std::sync::PoisonError::into_inner)
+            },
+        }
+    }
+}
+
+/// Returns `true` if the type path is a bare name or is `std::{module}::Name`/`core::{module}::Name`,
+/// leading `::` included
+pub fn is_std_path(path: &Path, module: &[&str]) -> bool {
+    let segments = path
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect::<Vec<_>>();
+
+    match segments.split_last() {
+        Some((_, [])) | None => true,
+        Some((_, [root, rest @ ..])) => (root == "std" || root == "core") && rest == module,
+    }
+}
+
+/// Returns `true` if the type is known to be `Copy`: primitives, shared references, pointers
+/// and tuples, arrays and `Option`s of them. Other types are assumed not to be `Copy`
+fn is_copy(ty: &Type) -> bool {
+    match ty {
+        Type::Reference(reference) => reference.mutability.is_none(),
+        Type::Ptr(_) | Type::BareFn(_) | Type::Never(_) => true,
+        Type::Paren(paren) => is_copy(&paren.elem),
+        Type::Group(group) => is_copy(&group.elem),
+        Type::Array(array) => is_copy(&array.elem),
+        Type::Tuple(tuple) => tuple.elems.iter().all(is_copy),
+        Type::Path(path) if path.qself.is_none() => {
+            let Some(segment) = path.path.segments.last() else {
+                return false;
+            };
+
+            match &segment.arguments {
+                PathArguments::None => matches!(
+                    segment.ident.to_string().as_str(),
+                    "bool"
+                        | "char"
+                        | "f32"
+                        | "f64"
+                        | "i8"
+                        | "i16"
+                        | "i32"
+                        | "i64"
+                        | "i128"
+                        | "isize"
+                        | "u8"
+                        | "u16"
+                        | "u32"
+                        | "u64"
+                        | "u128"
+                        | "usize"
+                ),
+                PathArguments::AngleBracketed(args) if segment.ident == "Option" => {
+                    args.args.iter().all(|arg| match arg {
+                        GenericArgument::Type(ty) => is_copy(ty),
+                        _ => false,
+                    })
+                }
+                _ => false,
+            }
+        }
+        _ => false,
+    }
+}
+
+/// Returns the primitive type stored by the atomic type, e.g. `u64` for `AtomicU64`
+fn atomic_primitive(ident: &Ident) -> Option<Type> {
+    let primitive = match ident.to_string().as_str() {
+        "AtomicBool" => quote! { bool },
+        "AtomicI8" => quote! { i8 },
+        "AtomicI16" => quote! { i16 },
+        "AtomicI32" => quote! { i32 },
+        "AtomicI64" => quote! { i64 },
+        "AtomicIsize" => quote! { isize },
+        "AtomicU8" => quote! { u8 },
+        "AtomicU16" => quote! { u16 },
+        "AtomicU32" => quote! { u32 },
+        "AtomicU64" => quote! { u64 },
+        "AtomicUsize" => quote! { usize },
+        _ => return None,
+    };
+
+    Some(parse_quote!(#primitive))
+}
@@ -25,10 +25,13 @@
 //! They have common arguments:
 //! - include=[...]
 //! - exclude=[...]
+//! - types=[...]
+//! - groups=[...]
 //! - pub
 //! - prefix=...
 //! ###
-//! - include=\[...\] - generate getter/setters only for the listed fields.
+//! - include=\[...\] - generate getter/setters only for the listed fields. `includes` is an alias
+//!
 //! For example:
 //! ```rust,ignore
 //! #[derive(trl)]
@@ -39,7 +42,8 @@
 //!
 //! Would generate getters/setters for `name` and `email` fields.
 //!
-//! - exclude=\[...\] - generate getters/setters for all fields except the listed.
+//! - exclude=\[...\] - generate getters/setters for all fields except the listed. `excludes` is an alias
+//!
 //! For example
 //!
 //! ```rust,ignore
@@ -51,10 +55,27 @@
 //!
 //! Would generate getters/setters only for the `id` field.
 //!
+//! - types=\[...\] - generate getters/setters for the fields of the listed types. `_` matches any type.
+//!   `exclude_types=[...]` skips them instead. `includes`/`excludes` also take globs, e.g. `"*_id"`
+//! - groups=\[...\] - generate getters/setters for the fields of the listed groups, set with `#[trl(group = ...)]`.
+//!   `exclude_groups=[...]` skips them instead
+//!
+//! For example
+//! ```rust,ignore
+//! #[derive(trl)]
+//! #[getters(includes = ["*_id", "created_*"])]
+//! #[setters(types = [String, Option<_>])]
+//! struct Test { /* ... */ }
+//! ```
+//!
+//! A glob or a type that matches no field is a compile error
+//!
 //! - pub - include public fields.
+//!
 //! By default public fields are ignored, but you can specify the `pub` argument to generate getters/setters for them too
 //!
 //! - prefix=... - generates getters/setters with specified prefix.
+//!
 //! For example
 //! ```rust,ignore
 //! #[derive(trl)]
@@ -64,6 +85,7 @@
 //! ```
 //! Would generate getters:
 //! - `get_id()`, `get_name()`, `get_email()`
+//!
 //! and setters:
 //! - `set_id()`, `set_name()`, `set_email()`
 //!
@@ -77,26 +99,181 @@
 
 mod accessors;
 mod attribute_parser;
+mod compare;
 mod constructor;
+mod debug;
+mod default;
+mod delegate;
+mod display;
+mod field_selection;
 mod helpers;
+mod hook;
+mod interior;
+mod method_attrs;
+mod method_registry;
 mod modifier;
 mod new_from_args;
+mod ordering;
+mod partial;
+mod parts;
+mod pin;
+mod selection;
+mod shared;
+mod track;
+mod trl_attr;
+mod value;
 
 use attribute_parser::generate_impl_for_struct;
 use syn::{ItemStruct, parse_macro_input};
 
 /// Default macro which is required by all others
+///
+/// Generated getters, setters and constructors are documented, e.g. "Returns the `id`",
+/// followed by the field's own doc comments
+///
+/// `#[cfg]`, `#[deprecated]` and `#[cfg_attr]` of a field are copied onto methods generated for it.
+/// `#[cfg_attr]` is copied only if it wraps attributes that apply to methods, e.g. `deprecated` or `allow`
+///
+/// A method name generated by two attributes, e.g. `#[getters(prefix = set_)]` and `#[setters]`,
+/// is a compile error naming both attributes
+///
+/// An unknown argument of any attribute, e.g. `getters` or `debug`, is a compile error
+/// listing the valid arguments and suggesting the closest one, e.g. ``Did you mean `prefix`?``.
+/// An argument used in an accessor attribute that doesn't take it, e.g. `#[get(track)]`, is a compile error
+/// listing the arguments of that attribute
+///
+/// Every struct-level attribute, e.g. `getters`, `debug`, `eq` or `parts`, selects fields the same way:
+/// `includes`/`excludes` take field names and globs, e.g. `"*_id"`, and `types`/`exclude_types` take types
+///
+/// Names in `includes`/`excludes` must be fields of the struct, an unknown name is a compile error.
+/// A field listed in both arguments, or a public field included without `pub`, produces a warning
 /// ### Attributes:
 /// - `#[get]` - generate a getter for a single field
 /// - `#[set]` - generate a setter for a single field
+/// - `#[dirty]` - mark the field that stores dirty flags for `#[setters(track)]`
+/// - `#[debug(...)]` - generate `Debug` impl, see below
+/// - `#[redact]` / `#[redact(hash)]` - print the field as `"***"` or as a hash prefix in `#[debug]`
+/// - `#[delegate(...)]` - generate methods forwarded to the field, see below
+/// - `#[deref]` - generate `Deref` and `DerefMut` impls to the field
+/// - `#[pin]` - structurally pin the field, see below
+/// - `#[trl(group = ...)]` - add the field to a group, selected with `groups`/`exclude_groups`
+/// - `#[trl(default)]` / `#[trl(default = expr)]` - generate `Default` impl or set the default value of a field, see below
 ///
 /// ### Common arguments
 /// - name = ... - generate a getter/setter with the specified name
 /// - prefix = ... - generate a getter/setter with the specified prefix
+/// - suffix = ... - generate a getter/setter with the specified suffix
+/// - doc = "..." - replace the generated docs of the getter/setter. `getters`/`setters` don't take it
+/// - attrs(...) - add attributes to the getter/setter, e.g. `attrs(inline, must_use)`
+/// - inline / inline(always) - add the `inline` attribute to the getter/setter
 ///
 /// ### Special agruments for `get`
 /// - ref / mut ref / move - specify the self modifier
-#[proc_macro_derive(trl, attributes(get, set))]
+/// - const - generate `const fn` getter
+/// - both - generate a `&mut self` getter besides the `&self` one
+/// - mut_suffix = ... - suffix of the `&mut self` getter and of the `RefCell`/`Rc`/`Arc` mutable getter. Default value is `_mut`
+/// - shared - generate `Rc`/`Arc` getters, see `getters`
+///
+/// ### Special agruments for `set`
+/// - notify = path::to::callback - call `callback("field", &old, &new)` after the assignment
+/// - map = path::to::function - transform the value with `function(value)` before it is stored.
+///   Only for `#[set]`, `#[setters(map = ...)]` is a compile error
+/// - validate = path::to::validator - check the value with `validator(&value)`.
+///   The setter returns `Result<(), E>` and leaves the field untouched on error
+/// - error = ... - the error type of the validator. Default type is `String`
+///
+/// ### Interior mutability
+/// Fields of type `Cell<T>`, `RefCell<T>`, `Mutex<T>` or `RwLock<T>` get accessors of the wrapped value:
+/// - `Cell<T>` - `get_x(&self) -> T`
+/// - `RefCell<T>` - `x(&self) -> Ref<T>` and `x_mut(&self) -> RefMut<T>`
+/// - `Mutex<T>` - `lock_x(&self) -> MutexGuard<T>`
+/// - `RwLock<T>` - `read_x(&self) -> RwLockReadGuard<T>` and `write_x(&self) -> RwLockWriteGuard<T>`
+///
+/// Setters take `&self` and store the value through the wrapper. Poisoned locks are recovered
+///
+/// Only std wrappers are recognised, a bare name is assumed to be imported from std,
+/// so `parking_lot::Mutex<T>` gets regular accessors.
+/// `Cell<T>` gets `get_x` only if `T` is known to be `Copy`, e.g. a primitive, otherwise the regular getter
+///
+/// Atomic fields, e.g. `AtomicU64`, get `x(&self) -> u64` doing `load` and `set_x(&self, value)` doing `store`.
+/// Setters also generate `swap_x`, `compare_exchange_x` and, for integers, `fetch_add_x`.
+/// The ordering is specified with `ordering = Relaxed | Acquire | Release | AcqRel | SeqCst`
+/// on `getters`/`setters` or `get`/`set`, default is `SeqCst`.
+/// Loads and stores use the strongest ordering allowed for them, e.g. `AcqRel` loads with `Acquire`
+///
+/// ### Debug
+/// `#[debug(...)]` is a helper attribute of `trl` which generates `Debug` impl.
+/// It must be placed after `#[derive(trl)]`. Struct-level arguments:
+/// - includes=\[...\] - print only the listed fields
+/// - excludes=\[...\] - print all fields except the listed
+///
+/// Field-level arguments:
+/// - skip - omit the field
+/// - with = path::to::function - format the field with `function(&value, f)`
+///
+/// For example:
+/// ```rust,ignore
+/// #[derive(trl)]
+/// #[debug]
+/// struct Test {
+///     name: String,
+///     #[redact]
+///     password: String,
+///     #[debug(skip)]
+///     cache: Vec<u8>,
+/// }
+/// ```
+/// Would print `Test { name: "John", password: "***", .. }`
+///
+/// ### Default
+/// `#[trl(default)]` on a struct generates `Default` impl.
+/// On a field `#[trl(default = expr)]` sets the default value of the field,
+/// other fields are initialized with `Default::default()`.
+/// The `trl` namespace keeps it apart from the `#[default]` of std `#[derive(Default)]`.
+///
+/// The same field defaults are used by `#[constructor]`:
+/// fields with `#[trl(default)]` or `#[trl(default = expr)]` are not constructor arguments.
+///
+/// For example:
+/// ```rust,ignore
+/// #[derive(trl)]
+/// #[trl(default)]
+/// #[constructor]
+/// struct Config {
+///     host: String,
+///     #[trl(default = 8080)]
+///     port: u16,
+/// }
+/// ```
+/// Would generate `Config::default()` with `port` equal to `8080` and `Config::new(host: String)`
+///
+/// ### Pin projection
+/// Fields marked with `#[pin]` are structurally pinned. The macro generates:
+/// - `{Struct}Projection` with `Pin<&mut T>` for pinned fields and `&mut T` for others
+/// - `project(self: Pin<&mut Self>) -> {Struct}Projection`
+/// - `x_pinned(self: Pin<&mut Self>) -> Pin<&mut T>` for every pinned field
+///
+/// The struct is `Unpin` only if all pinned fields are `Unpin`.
+/// It's a compile error to implement `Drop` or `Unpin` for the struct or to mark it with `#[repr(packed)]`
+///
+/// ### Delegate
+/// `#[delegate(...)]` takes a list of methods of the field type to forward.
+/// A method is specified with its signature, e.g. `get(&self, k: &K) -> Option<&V>`,
+/// or only by name for `len`, `is_empty`, `capacity`, `clear` and `shrink_to_fit`
+///
+/// For example:
+/// ```rust,ignore
+/// #[derive(trl)]
+/// struct Cache {
+///     #[delegate(len, is_empty, get(&self, k: &String) -> Option<&u64>)]
+///     inner: HashMap<String, u64>,
+/// }
+/// ```
+/// Would generate `len()`, `is_empty()` and `get()` methods calling the ones of `inner`
+#[proc_macro_derive(
+    trl,
+    attributes(get, set, dirty, debug, redact, delegate, deref, pin, trl)
+)]
 pub fn trl_derive(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(item as ItemStruct);
 
@@ -105,7 +282,8 @@ pub fn trl_derive(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
 
 /// Generate getters for a struct fields
 /// ### Common arguments
-/// - include=\[...\] - generate getter/setters only for the listed fields.
+/// - include=\[...\] - generate getter/setters only for the listed fields. `includes` is an alias
+///
 /// For example:
 /// ```rust,ignore
 /// #[derive(trl)]
@@ -116,7 +294,8 @@ pub fn trl_derive(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
 ///
 /// Would generate getters/setters for `name` and `email` fields.
 ///
-/// - exclude=\[...\] - generate getters/setters for all fields except the listed.
+/// - exclude=\[...\] - generate getters/setters for all fields except the listed. `excludes` is an alias
+///
 /// For example
 ///
 /// ```rust,ignore
@@ -128,10 +307,27 @@ pub fn trl_derive(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
 ///
 /// Would generate getters/setters only for the `id` field.
 ///
+/// - types=\[...\] - generate getters/setters for the fields of the listed types. `_` matches any type.
+///   `exclude_types=[...]` skips them instead. `includes`/`excludes` also take globs, e.g. `"*_id"`
+/// - groups=\[...\] - generate getters/setters for the fields of the listed groups, set with `#[trl(group = ...)]`.
+///   `exclude_groups=[...]` skips them instead
+///
+/// For example
+/// ```rust,ignore
+/// #[derive(trl)]
+/// #[getters(includes = ["*_id", "created_*"])]
+/// #[setters(types = [String, Option<_>])]
+/// struct Test { /* ... */ }
+/// ```
+///
+/// A glob or a type that matches no field is a compile error
+///
 /// - pub - include public fields.
+///
 /// By default public fields are ignored, but you can specify the `pub` argument to generate getters/setters for them too
 ///
 /// - prefix=... - generates getters/setters with specified prefix.
+///
 /// For example
 /// ```rust,ignore
 /// #[derive(trl)]
@@ -141,13 +337,20 @@ pub fn trl_derive(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
 /// ```
 /// Would generate getters:
 /// - `get_id()`, `get_name()`, `get_email()`
+///
 /// and setters:
 /// - `set_id()`, `set_name()`, `set_email()`
 ///
 /// Default value for getters is empty string, and for setters is `set_`
 ///
+/// - suffix=... - generates getters/setters with specified suffix, e.g. `suffix = _ref`.
+///
 /// ### Special agruments
 /// - ref / mut ref / move - specify the self modifier
+/// - const - generate `const fn` getters. It's a compile error with `move`, `trait`,
+///   `Rc`/`Arc`, interior mutability and atomic fields
+/// - both - generate `x_mut(&mut self) -> &mut T` besides `x(&self) -> &T`
+/// - mut_suffix=... - suffix of the `&mut self` getters and of the `RefCell`/`Rc`/`Arc` mutable getters. Default value is `_mut`
 ///
 /// For example:
 /// ```,rust,ignore
@@ -157,6 +360,32 @@ pub fn trl_derive(item: proc_macro::TokenStream) -> proc_macro::TokenStream {
 /// ```
 /// Would generate getters where `self` is taken as `&mut self`
 ///
+/// - ordering=... - memory ordering of atomic fields, e.g. `ordering = Acquire`. Default is `SeqCst`
+///
+/// - attrs(...) - add attributes to every generated method, e.g. `attrs(inline, must_use)`
+///
+/// - inline / inline(always) - add the `inline` attribute to every generated method
+///
+/// - shared - getters of `Rc<T>`/`Arc<T>` fields generate `x() -> &T`, `x_rc()`/`x_arc()` returning
+///   a cloned pointer and `x_mut() -> &mut T` using `make_mut`, so `T` must implement `Clone`
+///
+/// - trait=... - implement the getters as the specified trait instead of inherent methods.
+///   Use `#[trl_trait]` on one of the structs to define the trait with signatures of its getters
+///
+/// For example:
+/// ```rust,ignore
+/// #[derive(trl)]
+/// #[getters(trait = HasIdentity)]
+/// #[trl_trait]
+/// struct User { id: u64, name: String }
+///
+/// #[derive(trl)]
+/// #[getters(trait = HasIdentity)]
+/// struct Group { id: u64, name: String }
+/// ```
+/// Would define `trait HasIdentity { fn id(&self) -> &u64; fn name(&self) -> &String; }`
+/// and implement it for both structs
+///
 #[proc_macro_attribute]
 pub fn getters(
     _: proc_macro::TokenStream,
@@ -167,7 +396,8 @@ pub fn getters(
 
 /// Generate setters for a struct fields
 /// ### Common arguments
-/// - include=\[...\] - generate getter/setters only for the listed fields.
+/// - include=\[...\] - generate getter/setters only for the listed fields. `includes` is an alias
+///
 /// For example:
 /// ```rust,ignore
 /// #[derive(trl)]
@@ -178,7 +408,8 @@ pub fn getters(
 ///
 /// Would generate getters/setters for `name` and `email` fields.
 ///
-/// - exclude=\[...\] - generate getters/setters for all fields except the listed.
+/// - exclude=\[...\] - generate getters/setters for all fields except the listed. `excludes` is an alias
+///
 /// For example
 ///
 /// ```rust,ignore
@@ -190,10 +421,27 @@ pub fn getters(
 ///
 /// Would generate getters/setters only for the `id` field.
 ///
+/// - types=\[...\] - generate getters/setters for the fields of the listed types. `_` matches any type.
+///   `exclude_types=[...]` skips them instead. `includes`/`excludes` also take globs, e.g. `"*_id"`
+/// - groups=\[...\] - generate getters/setters for the fields of the listed groups, set with `#[trl(group = ...)]`.
+///   `exclude_groups=[...]` skips them instead
+///
+/// For example
+/// ```rust,ignore
+/// #[derive(trl)]
+/// #[getters(includes = ["*_id", "created_*"])]
+/// #[setters(types = [String, Option<_>])]
+/// struct Test { /* ... */ }
+/// ```
+///
+/// A glob or a type that matches no field is a compile error
+///
 /// - pub - include public fields.
+///
 /// By default public fields are ignored, but you can specify the `pub` argument to generate getters/setters for them too
 ///
 /// - prefix=... - generates getters/setters with specified prefix.
+///
 /// For example
 /// ```rust,ignore
 /// #[derive(trl)]
@@ -203,21 +451,73 @@ pub fn getters(
 /// ```
 /// Would generate getters:
 /// - `get_id()`, `get_name()`, `get_email()`
+///
 /// and setters:
 /// - `set_id()`, `set_name()`, `set_email()`
 ///
 /// Default value for getters is empty string, and for setters is `set_`
 ///
+/// - suffix=... - generates getters/setters with specified suffix, e.g. `suffix = _ref`.
+///
 /// ### Special agruments
-/// - ref / mut ref / move - specify the self modifier
+/// - ordering=... - memory ordering of atomic fields, e.g. `ordering = Acquire`. Default is `SeqCst`
+///
+/// - attrs(...) - add attributes to every generated method, e.g. `attrs(inline, must_use)`
+///
+/// - inline / inline(always) - add the `inline` attribute to every generated method
+///
+/// ### Change notification
+/// - notify=method_name - every setter calls `self.method_name("field", &old, &new)` after the assignment.
+///   The old value is moved out of the field, so it doesn't require `Clone`.
+///   As it's called for every field, the method is usually generic or takes `&dyn Any`
 ///
 /// For example:
 /// ```rust,ignore
 /// #[derive(trl)]
-/// #[getters(mut ref)]
+/// #[setters(notify = on_change)]
 /// struct Test { /* ... */ }
+///
+/// impl Test {
+///     fn on_change<T: Debug>(&self, field: &str, old: &T, new: &T) { /* ... */ }
+/// }
 /// ```
-/// Would generate getters where `self` is taken as `&mut self`
+///
+/// ### Validation
+/// - validate=method_name - every setter calls `self.method_name("field", &value)` before the assignment.
+///   The setters return `Result<(), E>` and leave the field untouched on error
+/// - error=... - the error type of the validator. Default type is `String`
+///
+/// For example:
+/// ```rust,ignore
+/// #[derive(trl)]
+/// #[setters(validate = check, error = MyError)]
+/// struct Test { /* ... */ }
+///
+/// impl Test {
+///     fn check<T>(&self, field: &str, value: &T) -> Result<(), MyError> { /* ... */ }
+/// }
+/// ```
+///
+/// ### Change tracking
+/// - track / track(eq) - every setter marks its field as dirty.
+///   With `track(eq)` the field is marked only if the new value is not equal to the old one
+///
+/// The dirty flags are stored as bits of an integer field marked with `#[dirty]`, which gets no setter.
+/// For example:
+/// ```rust,ignore
+/// #[derive(trl)]
+/// #[setters(track)]
+/// struct Test {
+///     id: u32,
+///     name: String,
+///     #[dirty]
+///     dirty: u64,
+/// }
+/// ```
+/// Would generate the `TestField` enum with a variant for each tracked field and methods:
+/// - `is_dirty()`, `is_id_dirty()`, `is_name_dirty()`
+/// - `dirty_fields() -> impl Iterator<Item = TestField>`
+/// - `clear_dirty()`
 ///
 #[proc_macro_attribute]
 pub fn setters(
@@ -230,7 +530,9 @@ pub fn setters(
 /// Generate default constructor for a struct
 /// ### Common arguments
 /// - name=... - generate a constructor with specified name.
+///
 /// Default name is `new`
+///
 /// For example:
 /// ```rust,ignore
 /// #[derive(trl)]
@@ -253,6 +555,15 @@ pub fn setters(
 ///
 /// Would generate constructor with `pub(crate)` visibility specifier.
 ///
+/// - doc="..." - replace the generated docs of the constructor.
+///   By default the docs list the constructor arguments with the field doc comments
+/// - attrs(...) - add attributes to the constructor, e.g. `attrs(must_use)`
+/// - inline / inline(always) - add the `inline` attribute to the constructor
+/// - const - generate `const fn` constructor. Fields with `#[trl(default)]` must specify a const value,
+///   e.g. `#[trl(default = 0)]`, since `Default::default()` is not const
+///
+/// Arguments of fields with `#[cfg]` are present only when the field is enabled
+///
 #[proc_macro_attribute]
 pub fn constructor(
     _: proc_macro::TokenStream,
@@ -260,3 +571,201 @@ pub fn constructor(
 ) -> proc_macro::TokenStream {
     item
 }
+
+/// Define the trait of `#[getters(trait = ...)]` with signatures of the generated getters
+///
+/// The trait has the same visibility as the struct. Other structs implementing the trait
+/// with `#[getters(trait = ...)]` must have getters with the same signatures.
+/// The trait takes the generics of the struct, e.g. `struct Label<'a, T>` defines `trait Labeled<'a, T>`
+///
+#[proc_macro_attribute]
+pub fn trl_trait(
+    _: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    item
+}
+
+/// Generate conversion of a struct to and from a map of dynamic values
+///
+/// Generates methods:
+/// - `to_map(&self) -> BTreeMap<String, Value>`
+/// - `from_map(&BTreeMap<String, Value>) -> Result<Self, FromValueError>`
+///
+/// and implements `ToValue`/`FromValue` for the struct, so it can be nested into other `#[value]` structs.
+/// Every field type must implement `ToValue` and `FromValue`.
+///
+/// For example:
+/// ```rust,ignore
+/// #[derive(trl)]
+/// #[value]
+/// struct Test { /* ... */ }
+/// ```
+///
+/// `from_map` collects every missing, extra and mistyped field into a single `FromValueError`.
+/// A missing `Option` field is read as `None`
+///
+#[proc_macro_attribute]
+pub fn value(_: proc_macro::TokenStream, item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    item
+}
+
+/// Generate a patch struct where every field is wrapped in `Option`
+/// ### Arguments
+/// - name=... - the patch struct name. Default name is `{Struct}Patch`
+/// - derive(...) - traits to derive for the patch struct. `Default` is always implemented
+/// - includes=\[...\] - add only the listed fields to the patch
+/// - excludes=\[...\] - add all fields except the listed to the patch
+///
+/// Public fields are added to the patch too, unless they are excluded.
+/// Generic parameters that no patch field uses are left out of the patch struct
+///
+/// For example:
+/// ```rust,ignore
+/// #[derive(trl)]
+/// #[partial(name = UserPatch, derive(Debug, Clone), excludes = [id])]
+/// struct User { /* ... */ }
+/// ```
+///
+/// Would generate the `UserPatch` struct with `pub` fields of type `Option<T>`, and methods:
+/// - `User::apply_patch(&mut self, patch: UserPatch)` - assigns every `Some` field of the patch
+/// - `User::diff(&self, other: &User) -> UserPatch` - creates a patch with the fields of `other` that differ from `self`
+/// - `UserPatch::is_empty(&self) -> bool` - returns `true` if every field is `None`
+///
+/// `diff` requires the fields to implement `PartialEq` and `Clone`
+///
+#[proc_macro_attribute]
+pub fn partial(
+    _: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    item
+}
+
+/// Generate `PartialEq` and `Eq` impls which compare only the selected fields
+/// ### Arguments
+/// - includes=\[...\] - compare only the listed fields
+/// - excludes=\[...\] - compare all fields except the listed
+/// - partial - generate only `PartialEq`, e.g. for structs with floating point fields
+///
+/// Public fields are compared too.
+///
+/// For example:
+/// ```rust,ignore
+/// #[derive(trl)]
+/// #[eq(includes = [id])]
+/// struct Test { /* ... */ }
+/// ```
+///
+/// Would generate `PartialEq` and `Eq` impls that compare only the `id` field
+///
+#[proc_macro_attribute]
+pub fn eq(_: proc_macro::TokenStream, item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    item
+}
+
+/// Generate `Hash` impl which hashes only the selected fields
+/// ### Arguments
+/// - includes=\[...\] - hash only the listed fields
+/// - excludes=\[...\] - hash all fields except the listed
+///
+/// For example:
+/// ```rust,ignore
+/// #[derive(trl)]
+/// #[eq(includes = [id])]
+/// #[hash(includes = [id])]
+/// struct Test { /* ... */ }
+/// ```
+///
+/// Would generate `Hash` impl that hashes only the `id` field.
+/// The selection should match the one of `#[eq]`, so equal values have equal hashes
+///
+#[proc_macro_attribute]
+pub fn hash(_: proc_macro::TokenStream, item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    item
+}
+
+/// Generate `PartialOrd` and `Ord` impls which compare the selected fields in order
+/// ### Arguments
+/// - by=\[...\] - compare the listed fields in the listed order.
+///   A field followed by `desc` is compared in reverse order
+/// - includes=\[...\] - compare only the listed fields in the declaration order
+/// - excludes=\[...\] - compare all fields except the listed in the declaration order
+/// - partial - generate only `PartialOrd`, e.g. for structs with floating point fields
+///
+/// For example:
+/// ```rust,ignore
+/// #[derive(trl)]
+/// #[eq(includes = [name, id])]
+/// #[ord(by = [name, id desc])]
+/// struct Test { /* ... */ }
+/// ```
+///
+/// Would generate `PartialOrd` and `Ord` impls that compare `name` and then `id` in reverse order.
+/// `Ord` requires `Eq`, which can be generated with `#[eq]`
+///
+#[proc_macro_attribute]
+pub fn ord(_: proc_macro::TokenStream, item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    item
+}
+
+/// Generate `Display` impl from a format template
+///
+/// The template is a format string where the placeholders are the struct field names.
+/// Format specs are passed through, e.g. `{id:08}` or `{price:.2}`.
+/// Unknown field names and positional placeholders are reported at compile time
+///
+/// For example:
+/// ```rust,ignore
+/// #[derive(trl)]
+/// #[display("User {name} <{email}>")]
+/// struct User { /* ... */ }
+/// ```
+///
+/// Would generate:
+/// ```rust,ignore
+/// impl Display for User {
+///     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+///         write!(f, "User {name} <{email}>", name = self.name, email = self.email)
+///     }
+/// }
+/// ```
+///
+#[proc_macro_attribute]
+pub fn display(
+    _: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    item
+}
+
+/// Generate conversions of a struct to and from a tuple of its fields
+/// ### Arguments
+/// - includes=\[...\] - add only the listed fields to the tuple, in the listed order
+/// - excludes=\[...\] - add all fields except the listed to the tuple
+///
+/// By default the tuple contains all fields in the declaration order, public fields included.
+/// A selection that leaves out a field generates only `into_parts`, `as_parts` and `From<Test> for (...)`,
+/// since the struct can't be created without the left out fields
+///
+/// For example:
+/// ```rust,ignore
+/// #[derive(trl)]
+/// #[parts]
+/// struct Test {
+///     id: u32,
+///     name: String,
+/// }
+/// ```
+///
+/// Would generate methods:
+/// - `into_parts(self) -> (u32, String)`
+/// - `as_parts(&self) -> (&u32, &String)`
+/// - `from_parts(parts: (u32, String)) -> Self`
+///
+/// and `From<Test> for (u32, String)`, `From<(u32, String)> for Test` impls
+///
+#[proc_macro_attribute]
+pub fn parts(_: proc_macro::TokenStream, item: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    item
+}
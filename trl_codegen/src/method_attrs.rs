@@ -0,0 +1,34 @@
+//! # method_attrs
+//!
+//! This module contains the `MethodAttrs` struct which holds the attributes of a generated method
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Field, Meta};
+
+use crate::helpers::{method_docs, propagated_attributes};
+
+/// User-specified attributes of a generated method
+#[derive(Debug, Clone, Default)]
+pub struct MethodAttrs {
+    /// Docs, replace the generated ones
+    pub doc: Option<String>,
+    /// Attributes added to the method, e.g. `inline` or `must_use`
+    pub attrs: Vec<Meta>,
+}
+
+impl MethodAttrs {
+    /// Generates the attributes of a method generated for the field:
+    /// docs, `cfg`/`cfg_attr`/`deprecated` attributes of the field and the user-specified attributes
+    pub fn tokens(&self, summary: &str, field: &Field) -> TokenStream {
+        let docs = method_docs(summary, field, self.doc.as_deref());
+        let propagated = propagated_attributes(field);
+        let attrs = &self.attrs;
+
+        quote! {
+            #docs
+            #(#propagated)*
+            #(#[#attrs])*
+        }
+    }
+}
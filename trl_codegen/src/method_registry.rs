@@ -0,0 +1,109 @@
+//! # method_registry
+//!
+//! This module contains the `MethodRegistry` struct which detects methods generated twice with the same name
+
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+use syn::{Attribute, Error, ImplItem, ItemImpl, parse2};
+
+/// Names of the generated methods with the attributes they come from
+#[derive(Default)]
+pub struct MethodRegistry {
+    /// Method name, description of the source attribute and the attribute itself
+    methods: Vec<(String, String, TokenStream)>,
+    /// Diagnostics of the duplicate methods
+    errors: TokenStream,
+}
+
+impl MethodRegistry {
+    /// Registers the methods generated by `attribute`, e.g. `` `#[getters]` ``
+    ///
+    /// Returns the methods without the ones already generated by another attribute,
+    /// which are reported with an error naming both sources and a note pointing at the first one
+    pub fn register(
+        &mut self,
+        methods: TokenStream,
+        source: String,
+        attribute: &Attribute,
+    ) -> TokenStream {
+        let Ok(parsed) = parse2::<ItemImpl>(quote! { impl Methods { #methods } }) else {
+            return methods;
+        };
+
+        let mut result = TokenStream::new();
+
+        for item in parsed.items {
+            if let ImplItem::Fn(method) = &item {
+                let name = method.sig.ident.to_string();
+
+                if let Some((_, first, first_attribute)) =
+                    self.methods.iter().find(|(n, _, _)| *n == name)
+                {
+                    let message = if *first == source {
+                        format!("Method `{}` is generated twice by {}", name, source)
+                    } else {
+                        format!(
+                            "Method `{}` is generated by both {} and {}",
+                            name, first, source
+                        )
+                    };
+
+                    let mut error = Error::new_spanned(attribute, message);
+                    error.combine(Error::new_spanned(
+                        first_attribute,
+                        format!("note: `{}` is first generated by {} here", name, first),
+                    ));
+
+                    self.errors.extend(error.to_compile_error());
+                    continue;
+                }
+
+                self.methods
+                    .push((name, source.clone(), attribute.to_token_stream()));
+            }
+
+            result.extend(quote! { #item });
+        }
+
+        result
+    }
+
+    /// Returns the diagnostics of the duplicate methods
+    pub fn errors(self) -> TokenStream {
+        self.errors
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syn::parse_quote;
+
+    #[test]
+    fn duplicate_methods_test() {
+        let mut registry = MethodRegistry::default();
+        let getters: Attribute = parse_quote!(#[getters]);
+        let get: Attribute = parse_quote!(#[get(name = id)]);
+
+        let first = registry.register(
+            quote! { pub fn id(&self) {} pub fn name(&self) {} },
+            String::from("`#[getters]`"),
+            &getters,
+        );
+        let second = registry.register(
+            quote! { #[cfg(all())] pub fn id(&self) {} pub fn email(&self) {} },
+            String::from("`#[get]` on `email`"),
+            &get,
+        );
+
+        assert!(first.to_string().contains("fn name") && first.to_string().contains("fn id"));
+        assert!(!second.to_string().contains("fn id") && second.to_string().contains("fn email"));
+
+        let errors = registry.errors().to_string();
+        assert!(
+            errors
+                .contains("Method `id` is generated by both `#[getters]` and `#[get]` on `email`")
+        );
+        assert!(errors.contains("note: `id` is first generated by `#[getters]` here"));
+    }
+}
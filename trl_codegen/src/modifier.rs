@@ -5,8 +5,8 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 use syn::{
-    parse::{Parse, ParseStream},
     Token,
+    parse::{Parse, ParseStream},
 };
 
 /// Represents the `self` modifier
@@ -38,9 +38,9 @@ impl Parse for Modifier {
     }
 }
 
-impl Into<TokenStream> for Modifier {
-    fn into(self) -> TokenStream {
-        match self {
+impl From<Modifier> for TokenStream {
+    fn from(val: Modifier) -> Self {
+        match val {
             Modifier::Move => quote!(),
             Modifier::Ref => quote!(&),
             Modifier::MutRef => quote!(&mut),
@@ -0,0 +1,80 @@
+//! # ordering
+//!
+//! This module contains the `AtomicOrdering` enum which represents the memory ordering of atomic accessors
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Error, Expr, spanned::Spanned};
+
+/// Represents the memory ordering of atomic accessors
+///
+/// Loads and stores use the strongest ordering allowed for them, e.g. `AcqRel` loads with `Acquire`
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum AtomicOrdering {
+    /// ordering = Relaxed
+    Relaxed,
+    /// ordering = Acquire
+    Acquire,
+    /// ordering = Release
+    Release,
+    /// ordering = AcqRel
+    AcqRel,
+    /// ordering = SeqCst
+    #[default]
+    SeqCst,
+}
+
+impl AtomicOrdering {
+    /// Parses the ordering name, e.g. `SeqCst`
+    pub fn from_expr(expr: &Expr) -> syn::Result<Self> {
+        let name = match expr {
+            Expr::Path(path) => path.path.get_ident().map(|ident| ident.to_string()),
+            _ => None,
+        };
+
+        match name.as_deref() {
+            Some("Relaxed") => Ok(AtomicOrdering::Relaxed),
+            Some("Acquire") => Ok(AtomicOrdering::Acquire),
+            Some("Release") => Ok(AtomicOrdering::Release),
+            Some("AcqRel") => Ok(AtomicOrdering::AcqRel),
+            Some("SeqCst") => Ok(AtomicOrdering::SeqCst),
+            _ => Err(Error::new(
+                expr.span(),
+                "expected one of: `Relaxed`, `Acquire`, `Release`, `AcqRel`, `SeqCst`",
+            )),
+        }
+    }
+
+    /// Ordering of read-modify-write operations, e.g. `swap`
+    pub fn rmw(&self) -> TokenStream {
+        Self::tokens(*self)
+    }
+
+    /// Ordering of `load` and of the failed `compare_exchange`
+    pub fn load(&self) -> TokenStream {
+        Self::tokens(match self {
+            AtomicOrdering::Release => AtomicOrdering::Relaxed,
+            AtomicOrdering::AcqRel => AtomicOrdering::Acquire,
+            ordering => *ordering,
+        })
+    }
+
+    /// Ordering of `store`
+    pub fn store(&self) -> TokenStream {
+        Self::tokens(match self {
+            AtomicOrdering::Acquire => AtomicOrdering::Relaxed,
+            AtomicOrdering::AcqRel => AtomicOrdering::Release,
+            ordering => *ordering,
+        })
+    }
+
+    fn tokens(ordering: AtomicOrdering) -> TokenStream {
+        match ordering {
+            AtomicOrdering::Relaxed => quote! { ::std::sync::atomic::Ordering::Relaxed },
+            AtomicOrdering::Acquire => quote! { ::std::sync::atomic::Ordering::Acquire },
+            AtomicOrdering::Release => quote! { ::std::sync::atomic::Ordering::Release },
+            AtomicOrdering::AcqRel => quote! { ::std::sync::atomic::Ordering::AcqRel },
+            AtomicOrdering::SeqCst => quote! { ::std::sync::atomic::Ordering::SeqCst },
+        }
+    }
+}
@@ -0,0 +1,6 @@
+mod partial_arg;
+pub mod partial_processor;
+mod partial_struct_attrs;
+
+pub use partial_arg::*;
+pub use partial_struct_attrs::*;
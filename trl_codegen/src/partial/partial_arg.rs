@@ -0,0 +1,95 @@
+//! # partial_arg
+//! This module contains the `PartialArg` enum which represents a single argument of a partial attribute
+//!
+
+use syn::{
+    Error, Meta, MetaList, MetaNameValue, Path, Result, Token,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+};
+
+use crate::{accessors::AccessorArg, helpers::unknown_arg_error, selection::SelectionArg};
+
+/// Names of all partial arguments, listed by the unknown argument error
+const ARG_NAMES: &[&str] = &[
+    "name",
+    "derive",
+    "includes",
+    "excludes",
+    "types",
+    "exclude_types",
+    "groups",
+    "exclude_groups",
+];
+
+/// Enum PartialArg represents a single argument of a partial attribute
+pub enum PartialArg {
+    /// The patch struct name. The default value is `{Struct}Patch`
+    Name(String),
+    /// Traits to derive for the patch struct
+    Derive(Vec<Path>),
+    /// Fields to include or exclude, e.g. `includes = [...]` or `types = [...]`
+    Selection(SelectionArg),
+}
+
+impl PartialArg {
+    /// If the parsed value is MetaNameValue, this means its the construction like: name = value.
+    /// This method parses this construction into an `Arg`
+    pub fn nv_to_arg(nv: &MetaNameValue) -> Result<Self> {
+        let ident = nv
+            .path
+            .get_ident()
+            .ok_or_else(|| Error::new(nv.path.span(), "Wrong name. Expected identifier"))?;
+
+        match ident.to_string().as_str() {
+            "name" => Ok(PartialArg::Name(AccessorArg::ident_to_string(&nv.value)?)),
+            _ => Err(unknown_arg_error(ident, ARG_NAMES)),
+        }
+    }
+
+    /// If the parsed value is MetaList, this means its the construction like: derive(...).
+    /// This method parses this construction into an `Arg`
+    pub fn list_to_arg(list: &MetaList) -> Result<Self> {
+        let ident = list
+            .path
+            .get_ident()
+            .ok_or_else(|| Error::new(list.path.span(), "Wrong name. Expected identifier"))?;
+
+        if ident != "derive" {
+            return Err(unknown_arg_error(ident, ARG_NAMES));
+        }
+
+        let paths = list.parse_args_with(Punctuated::<Path, Token![,]>::parse_terminated)?;
+
+        Ok(PartialArg::Derive(paths.into_iter().collect()))
+    }
+}
+
+impl Parse for PartialArg {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if SelectionArg::peek(input) {
+            return Ok(PartialArg::Selection(input.parse()?));
+        }
+
+        let arg = match input.parse::<Meta>() {
+            Ok(Meta::NameValue(nv)) => PartialArg::nv_to_arg(&nv)?,
+            Ok(Meta::List(list)) => PartialArg::list_to_arg(&list)?,
+            Ok(Meta::Path(path)) => {
+                let ident = path
+                    .get_ident()
+                    .ok_or_else(|| Error::new(path.span(), "Wrong name. Expected identifier"))?;
+
+                return Err(unknown_arg_error(ident, ARG_NAMES));
+            }
+            _ => {
+                return Result::Err(Error::new(
+                    input.span(),
+                    "Could not parse arg. Expected name-value or derive(...)",
+                ));
+            }
+        };
+
+        Ok(arg)
+    }
+}
@@ -0,0 +1,200 @@
+//! # partial_processor
+//! This module contains functions that generate the patch struct and its methods
+
+use std::collections::HashSet;
+
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{ToTokens, format_ident, quote};
+use syn::{GenericParam, Generics, ItemStruct, WherePredicate, punctuated::Punctuated};
+
+use crate::{
+    helpers::{fill_includes_if_empty, should_field_be_added},
+    partial::PartialStructAttrs,
+};
+
+/// Generates `apply_patch` and `diff` methods
+pub fn process_partial(mut attrs: PartialStructAttrs, input: &ItemStruct) -> TokenStream {
+    fill_includes_if_empty(&mut attrs.selection.includes, &input.fields);
+
+    let patch_name = patch_name(&attrs, input);
+    let patch_generics = patch_generics(&attrs, input);
+    let (_, ty_generics, _) = patch_generics.split_for_impl();
+
+    let mut apply_fields = TokenStream::new();
+    let mut diff_fields = TokenStream::new();
+
+    input.fields.iter().for_each(|field| {
+        if !should_field_be_added(&attrs, field) {
+            return;
+        }
+
+        let field_name = field.ident.clone().expect("Error, expected named field");
+
+        apply_fields.extend(quote! {
+            if let ::std::option::Option::Some(value) = patch.#field_name {
+                self.#field_name = value;
+            }
+        });
+
+        diff_fields.extend(quote! {
+            #field_name: if self.#field_name != other.#field_name {
+                ::std::option::Option::Some(::std::clone::Clone::clone(&other.#field_name))
+            } else {
+                ::std::option::Option::None
+            },
+        });
+    });
+
+    quote! {
+        /// Sets the fields that are `Some` in the patch
+        pub fn apply_patch(&mut self, patch: #patch_name #ty_generics) {
+            #apply_fields
+        }
+
+        /// Returns the patch that turns `self` into `other`
+        pub fn diff(&self, other: &Self) -> #patch_name #ty_generics {
+            #patch_name { #diff_fields }
+        }
+    }
+}
+
+/// Generates the patch struct with its `is_empty` method and `Default` impl
+pub fn process_partial_struct(mut attrs: PartialStructAttrs, input: &ItemStruct) -> TokenStream {
+    fill_includes_if_empty(&mut attrs.selection.includes, &input.fields);
+
+    let patch_name = patch_name(&attrs, input);
+    let vis = &input.vis;
+    let generics = patch_generics(&attrs, input);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut patch_fields = TokenStream::new();
+    let mut default_fields = TokenStream::new();
+    let mut is_empty_checks = Vec::new();
+
+    input.fields.iter().for_each(|field| {
+        if !should_field_be_added(&attrs, field) {
+            return;
+        }
+
+        let field_name = field.ident.clone().expect("Error, expected named field");
+        let field_ty = &field.ty;
+
+        let doc = format!("New value of the `{}` field", field_name);
+
+        patch_fields.extend(quote! {
+            #[doc = #doc]
+            pub #field_name: ::std::option::Option<#field_ty>,
+        });
+
+        default_fields.extend(quote! {
+            #field_name: ::std::option::Option::None,
+        });
+
+        is_empty_checks.push(quote! { self.#field_name.is_none() });
+    });
+
+    // `Default` is always implemented, so it is removed from the derive list
+    let derives = attrs
+        .derives
+        .iter()
+        .filter(|d| !d.is_ident("Default"))
+        .collect::<Vec<_>>();
+
+    let doc = format!(
+        "Partial update of [`{}`], `None` fields are left unchanged",
+        input.ident
+    );
+
+    quote! {
+        #[doc = #doc]
+        #[derive(#(#derives),*)]
+        #vis struct #patch_name #generics #where_clause {
+            #patch_fields
+        }
+
+        impl #impl_generics #patch_name #ty_generics #where_clause {
+            /// Returns `true` if the patch doesn't change any field
+            pub fn is_empty(&self) -> bool {
+                true #(&& #is_empty_checks)*
+            }
+        }
+
+        impl #impl_generics ::std::default::Default for #patch_name #ty_generics #where_clause {
+            fn default() -> Self {
+                Self { #default_fields }
+            }
+        }
+    }
+}
+
+/// Returns the patch struct name
+fn patch_name(attrs: &PartialStructAttrs, input: &ItemStruct) -> syn::Ident {
+    if attrs.name.is_empty() {
+        format_ident!("{}Patch", input.ident)
+    } else {
+        format_ident!("{}", attrs.name)
+    }
+}
+
+/// Returns the generics of the struct used by the patch fields
+///
+/// Parameters that no selected field mentions are removed, with the where predicates using them
+fn patch_generics(attrs: &PartialStructAttrs, input: &ItemStruct) -> Generics {
+    let mut used = HashSet::new();
+
+    input
+        .fields
+        .iter()
+        .filter(|field| should_field_be_added(attrs, field))
+        .for_each(|field| collect_idents(field.ty.to_token_stream(), &mut used));
+
+    let mut generics = input.generics.clone();
+    let mut removed = HashSet::new();
+
+    generics.params = generics
+        .params
+        .into_iter()
+        .filter(|param| {
+            let name = match param {
+                GenericParam::Type(param) => param.ident.to_string(),
+                GenericParam::Lifetime(param) => param.lifetime.ident.to_string(),
+                GenericParam::Const(param) => param.ident.to_string(),
+            };
+
+            if used.contains(&name) {
+                return true;
+            }
+
+            removed.insert(name);
+            false
+        })
+        .collect();
+
+    if let Some(where_clause) = &mut generics.where_clause {
+        where_clause.predicates = where_clause
+            .predicates
+            .iter()
+            .filter(|predicate| {
+                let mut idents = HashSet::new();
+                collect_idents(predicate.to_token_stream(), &mut idents);
+                idents.is_disjoint(&removed)
+            })
+            .cloned()
+            .collect::<Punctuated<WherePredicate, _>>();
+    }
+
+    generics
+}
+
+/// Collects the identifiers of the tokens, lifetimes are collected without `'`
+fn collect_idents(tokens: TokenStream, idents: &mut HashSet<String>) {
+    for token in tokens {
+        match token {
+            TokenTree::Ident(ident) => {
+                idents.insert(ident.to_string());
+            }
+            TokenTree::Group(group) => collect_idents(group.stream(), idents),
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,54 @@
+//! # partial_struct_attrs
+//! This module contains the `PartialStructAttrs` struct which represents the args of a partial attribute
+//!
+
+use syn::{Path, Token, punctuated::Punctuated};
+
+use crate::{
+    field_selection::FieldSelection, new_from_args::NewFromArgs, partial::PartialArg,
+    selection::SelectionAttrs,
+};
+
+/// Struct `PartialStructAttrs` represents the args of a partial attribute
+#[derive(Clone)]
+pub struct PartialStructAttrs {
+    /// Patch struct name, empty means `{Struct}Patch`
+    pub name: String,
+    /// Traits to derive for the patch struct
+    pub derives: Vec<Path>,
+    /// Fields to include and exclude
+    pub selection: SelectionAttrs,
+}
+
+impl NewFromArgs<PartialArg> for PartialStructAttrs {
+    fn new(args: Punctuated<PartialArg, Token![,]>) -> Self {
+        let mut name = String::new();
+        let mut derives = Vec::new();
+        let mut selection = SelectionAttrs::default();
+
+        for value in args {
+            match value {
+                PartialArg::Name(n) => name = n,
+                PartialArg::Derive(d) => derives.extend(d),
+                PartialArg::Selection(s) => selection.add(s),
+            }
+        }
+
+        Self {
+            name,
+            derives,
+            selection,
+        }
+    }
+}
+
+impl FieldSelection for PartialStructAttrs {
+    fn selection(&self) -> &SelectionAttrs {
+        &self.selection
+    }
+
+    // A patch may update public fields too
+    fn include_pub(&self) -> bool {
+        true
+    }
+}
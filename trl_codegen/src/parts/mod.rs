@@ -0,0 +1,6 @@
+mod parts_arg;
+pub mod parts_processor;
+mod parts_struct_attrs;
+
+pub use parts_arg::*;
+pub use parts_struct_attrs::*;
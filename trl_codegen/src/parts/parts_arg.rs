@@ -0,0 +1,40 @@
+//! # parts_arg
+//! This module contains the `PartsArg` enum which represents a single argument of a parts attribute
+//!
+
+use syn::{
+    Error, Ident, Result,
+    parse::{Parse, ParseStream},
+};
+
+use crate::{helpers::unknown_arg_error, selection::SelectionArg};
+
+/// Names of all parts arguments, listed by the unknown argument error
+const ARG_NAMES: &[&str] = &[
+    "includes",
+    "excludes",
+    "types",
+    "exclude_types",
+    "groups",
+    "exclude_groups",
+];
+
+/// Enum PartsArg represents a single argument of a parts attribute
+pub enum PartsArg {
+    /// Fields to include or exclude, e.g. `includes = [...]` or `types = [...]`
+    Selection(SelectionArg),
+}
+
+impl Parse for PartsArg {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if SelectionArg::peek(input) {
+            return Ok(PartsArg::Selection(input.parse()?));
+        }
+
+        if input.peek(Ident) {
+            return Err(unknown_arg_error(&input.parse::<Ident>()?, ARG_NAMES));
+        }
+
+        Err(Error::new(input.span(), "Could not parse arg"))
+    }
+}
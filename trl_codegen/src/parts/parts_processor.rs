@@ -0,0 +1,102 @@
+//! # parts_processor
+//! This module contains functions that generate conversions of a struct to and from a tuple of its fields
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{Field, ItemStruct};
+
+use crate::{
+    helpers::{fill_includes_if_empty, should_field_be_added},
+    parts::PartsStructAttrs,
+};
+
+/// Generates `into_parts`, `as_parts` and `from_parts` methods
+pub fn process_parts(attrs: PartsStructAttrs, input: &ItemStruct) -> TokenStream {
+    let fields = selected_fields(attrs, input);
+    let names = fields.iter().map(|f| &f.ident).collect::<Vec<_>>();
+    let types = fields.iter().map(|f| &f.ty).collect::<Vec<_>>();
+    let list = names
+        .iter()
+        .map(|name| format!("`{}`", name.as_ref().unwrap()))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let into_doc = format!("Converts the struct into the tuple of {}", list);
+    let as_doc = format!("Returns the tuple of references to {}", list);
+    let from_doc = format!("Creates the struct from the tuple of {}", list);
+
+    let mut result = quote! {
+        #[doc = #into_doc]
+        pub fn into_parts(self) -> (#(#types,)*) {
+            (#(self.#names,)*)
+        }
+
+        #[doc = #as_doc]
+        pub fn as_parts(&self) -> (#(&#types,)*) {
+            (#(&self.#names,)*)
+        }
+    };
+
+    if fields.len() == input.fields.len() {
+        result.extend(quote! {
+            #[doc = #from_doc]
+            pub fn from_parts(parts: (#(#types,)*)) -> Self {
+                let (#(#names,)*) = parts;
+
+                Self { #(#names),* }
+            }
+        });
+    }
+
+    result
+}
+
+/// Generates `From` impls between the struct and the tuple of its fields
+pub fn process_parts_impls(attrs: PartsStructAttrs, input: &ItemStruct) -> TokenStream {
+    let fields = selected_fields(attrs, input);
+    let types = fields.iter().map(|f| &f.ty).collect::<Vec<_>>();
+
+    let struct_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut result = quote! {
+        impl #impl_generics ::std::convert::From<#struct_name #ty_generics> for (#(#types,)*) #where_clause {
+            fn from(value: #struct_name #ty_generics) -> Self {
+                value.into_parts()
+            }
+        }
+    };
+
+    if fields.len() == input.fields.len() {
+        result.extend(quote! {
+            impl #impl_generics ::std::convert::From<(#(#types,)*)> for #struct_name #ty_generics #where_clause {
+                fn from(parts: (#(#types,)*)) -> Self {
+                    Self::from_parts(parts)
+                }
+            }
+        });
+    }
+
+    result
+}
+
+/// Returns the selected fields in the order of `includes`, or in the declaration order if it's empty
+///
+/// Fields matched by a glob or a type are taken in the declaration order
+fn selected_fields(mut attrs: PartsStructAttrs, input: &ItemStruct) -> Vec<&Field> {
+    fill_includes_if_empty(&mut attrs.selection.includes, &input.fields);
+
+    let mut selected: Vec<&Field> = Vec::new();
+
+    for pattern in &attrs.selection.includes {
+        for field in input.fields.iter().filter(|field| pattern.matches(field)) {
+            if should_field_be_added(&attrs, field)
+                && !selected.iter().any(|added| added.ident == field.ident)
+            {
+                selected.push(field);
+            }
+        }
+    }
+
+    selected
+}
@@ -0,0 +1,42 @@
+//! # parts_struct_attrs
+//! This module contains the `PartsStructAttrs` struct which represents the args of a parts attribute
+//!
+
+use syn::{Token, punctuated::Punctuated};
+
+use crate::{
+    field_selection::FieldSelection, new_from_args::NewFromArgs, parts::PartsArg,
+    selection::SelectionAttrs,
+};
+
+/// Struct `PartsStructAttrs` represents the args of a parts attribute
+#[derive(Clone)]
+pub struct PartsStructAttrs {
+    /// Fields to include and exclude, the tuple follows the order of `includes`
+    pub selection: SelectionAttrs,
+}
+
+impl NewFromArgs<PartsArg> for PartsStructAttrs {
+    fn new(args: Punctuated<PartsArg, Token![,]>) -> Self {
+        let mut selection = SelectionAttrs::default();
+
+        for value in args {
+            match value {
+                PartsArg::Selection(s) => selection.add(s),
+            }
+        }
+
+        Self { selection }
+    }
+}
+
+impl FieldSelection for PartsStructAttrs {
+    fn selection(&self) -> &SelectionAttrs {
+        &self.selection
+    }
+
+    // All fields are moved out, regardless of the visibility
+    fn include_pub(&self) -> bool {
+        true
+    }
+}
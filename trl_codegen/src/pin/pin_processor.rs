@@ -0,0 +1,196 @@
+//! # pin_processor
+//! This module contains functions that generate pin projections for fields marked with `#[pin]`
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Attribute, Error, GenericParam, ItemStruct, Lifetime, parse_quote};
+
+use crate::helpers::has_attribute;
+
+/// Returns the `#[pin]` attribute of the first pinned field, if any
+pub fn pin_attribute(input: &ItemStruct) -> Option<&Attribute> {
+    input
+        .fields
+        .iter()
+        .flat_map(|field| &field.attrs)
+        .find(|attr| attr.path().is_ident("pin"))
+}
+
+/// Generates the `{Struct}Projection` struct, `project()`, `x_pinned()` accessors and the safety checks:
+/// - the struct is `Unpin` only if all pinned fields are `Unpin`
+/// - the struct must not implement `Drop`, since `drop` could move pinned fields
+/// - the struct must not be `#[repr(packed)]`, since its fields could be moved to be aligned
+///
+/// Methods are written to `stream`, the rest is written to `items`
+pub fn process_pin(input: &ItemStruct, stream: &mut TokenStream, items: &mut TokenStream) {
+    if let Some(packed) = input.attrs.iter().find(|attr| is_repr_packed(attr)) {
+        items.extend(
+            Error::new_spanned(
+                packed,
+                "`#[pin]` fields can't be used in a `#[repr(packed)]` struct",
+            )
+            .to_compile_error(),
+        );
+        return;
+    }
+
+    let struct_name = &input.ident;
+    let vis = &input.vis;
+    let projection = format_ident!("{}Projection", struct_name);
+    let pinned_fields = format_ident!("__TrlPinnedFields{}", struct_name);
+    let pin: Lifetime = parse_quote!('__pin);
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut projection_generics = input.generics.clone();
+    projection_generics
+        .params
+        .insert(0, GenericParam::Lifetime(parse_quote!(#pin)));
+    projection_generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote!(#struct_name #ty_generics: #pin));
+    let (projection_impl_generics, projection_ty_generics, projection_where_clause) =
+        projection_generics.split_for_impl();
+
+    let mut unpin_generics = projection_generics.clone();
+    unpin_generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote!(#pinned_fields #projection_ty_generics: ::std::marker::Unpin));
+    let unpin_where_clause = &unpin_generics.where_clause;
+
+    let mut projection_fields = TokenStream::new();
+    let mut projected_values = TokenStream::new();
+    let mut pinned_types = TokenStream::new();
+    let mut accessors = TokenStream::new();
+    let mut names = Vec::new();
+
+    for field in input.fields.iter() {
+        let fin = field.ident.clone().expect("Error, expected named field");
+        let field_vis = &field.vis;
+        let ty = &field.ty;
+
+        if has_attribute(field, "pin") {
+            let accessor_name = format_ident!("{}_pinned", fin);
+            let field_doc = format!("Pinned reference to the `{}`", fin);
+            let accessor_doc = format!("Returns a pinned reference to the `{}`", fin);
+
+            projection_fields.extend(quote! {
+                #[doc = #field_doc]
+                #field_vis #fin: ::std::pin::Pin<&#pin mut #ty>,
+            });
+            projected_values.extend(quote! {
+                #fin: ::std::pin::Pin::new_unchecked(#fin),
+            });
+            pinned_types.extend(quote! {
+                #fin: #ty,
+            });
+            accessors.extend(quote! {
+                #[doc = #accessor_doc]
+                pub fn #accessor_name(self: ::std::pin::Pin<&mut Self>) -> ::std::pin::Pin<&mut #ty> {
+                    // SAFETY: the field is structurally pinned, the struct is `Unpin` only if the field is
+                    // and it can't implement `Drop` or be packed, so the field is never moved
+                    unsafe { self.map_unchecked_mut(|this| &mut this.#fin) }
+                }
+            });
+        } else {
+            let field_doc = format!("Mutable reference to the `{}`", fin);
+
+            projection_fields.extend(quote! {
+                #[doc = #field_doc]
+                #field_vis #fin: &#pin mut #ty,
+            });
+            projected_values.extend(quote! {
+                #fin,
+            });
+        }
+
+        names.push(fin);
+    }
+
+    let phantom_params = input
+        .generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Lifetime(lifetime) => {
+                let lifetime = &lifetime.lifetime;
+                Some(quote! { &#lifetime () })
+            }
+            GenericParam::Type(ty) => {
+                let ty = &ty.ident;
+                Some(quote! { fn() -> #ty })
+            }
+            GenericParam::Const(_) => None,
+        });
+
+    let projection_doc = format!(
+        "Projection of a pinned [`{}`] with pinned references to `#[pin]` fields",
+        struct_name
+    );
+
+    stream.extend(quote! {
+        /// Projects the pinned struct into references to its fields
+        pub fn project<#pin>(self: ::std::pin::Pin<&#pin mut Self>) -> #projection #projection_ty_generics {
+            // SAFETY: pinned fields are only exposed as `Pin<&mut T>`, other fields are not pinned
+            unsafe {
+                let Self { #(#names),* } = self.get_unchecked_mut();
+
+                #projection {
+                    #projected_values
+                }
+            }
+        }
+
+        #accessors
+    });
+
+    items.extend(quote! {
+        #[doc = #projection_doc]
+        #vis struct #projection #projection_impl_generics #projection_where_clause {
+            #projection_fields
+        }
+
+        #[allow(dead_code, non_camel_case_types)]
+        struct #pinned_fields #projection_impl_generics #projection_where_clause {
+            __phantom: ::std::marker::PhantomData<(&#pin () #(, #phantom_params)*)>,
+            #pinned_types
+        }
+
+        impl #projection_impl_generics ::std::marker::Unpin for #struct_name #ty_generics #unpin_where_clause {}
+
+        const _: () = {
+            trait PinnedStructMustNotImplementDrop {}
+
+            #[allow(drop_bounds)]
+            impl<T: ::std::ops::Drop> PinnedStructMustNotImplementDrop for T {}
+
+            impl #impl_generics PinnedStructMustNotImplementDrop for #struct_name #ty_generics #where_clause {}
+        };
+    });
+}
+
+/// Checks whether the attribute is `#[repr(packed)]` or `#[repr(packed(N))]`
+fn is_repr_packed(attr: &Attribute) -> bool {
+    if !attr.path().is_ident("repr") {
+        return false;
+    }
+
+    let mut packed = false;
+
+    let _ = attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("packed") {
+            packed = true;
+        }
+
+        if meta.input.peek(syn::token::Paren) {
+            let _content;
+            syn::parenthesized!(_content in meta.input);
+        }
+
+        Ok(())
+    });
+
+    packed
+}
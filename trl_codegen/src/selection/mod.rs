@@ -0,0 +1,5 @@
+mod selection_arg;
+mod selection_attrs;
+
+pub use selection_arg::*;
+pub use selection_attrs::*;
@@ -0,0 +1,124 @@
+//! # selection_arg
+//! This module contains the `SelectionArg` enum which represents a field selection argument shared by struct-level attributes
+//!
+
+use syn::{
+    Error, Ident, LitStr, Result, Token, Type, bracketed,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+};
+
+use crate::field_selection::FieldPattern;
+
+/// Enum SelectionArg represents a field selection argument, e.g. `includes = [...]` or `types = [...]`
+pub enum SelectionArg {
+    /// Fields to include, selected with `includes`, `types` or `groups`
+    Includes(Vec<FieldPattern>),
+    /// Fields to exclude, selected with `excludes`, `exclude_types` or `exclude_groups`
+    Excludes(Vec<FieldPattern>),
+}
+
+impl SelectionArg {
+    /// Names of all selection arguments, without the `include`/`exclude` aliases
+    pub const NAMES: &[&str] = &[
+        "includes",
+        "excludes",
+        "types",
+        "exclude_types",
+        "groups",
+        "exclude_groups",
+    ];
+
+    /// Checks whether the next argument is a selection argument
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek2(Token![=])
+            && input.fork().parse::<Ident>().is_ok_and(|ident| {
+                ident == "include"
+                    || ident == "exclude"
+                    || SelectionArg::NAMES.contains(&ident.to_string().as_str())
+            })
+    }
+
+    /// Parses the `[...]` list of field names and globs, e.g. `[id, "*_at"]`
+    fn parse_names(input: ParseStream) -> Result<Vec<FieldPattern>> {
+        let content;
+        bracketed!(content in input);
+
+        let mut patterns = Vec::new();
+
+        while !content.is_empty() {
+            if content.peek(LitStr) {
+                patterns.push(FieldPattern::Glob(content.parse()?));
+            } else if content.peek(Ident) {
+                patterns.push(FieldPattern::Name(content.parse()?));
+            } else {
+                return Err(Error::new(
+                    content.span(),
+                    "Expected field name or glob, e.g. `id` or `\"*_id\"`",
+                ));
+            }
+
+            if !content.is_empty() {
+                content.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(patterns)
+    }
+
+    /// Parses the `[...]` list of types, e.g. `[String, Option<_>]`
+    fn parse_types(input: ParseStream) -> Result<Vec<FieldPattern>> {
+        let content;
+        bracketed!(content in input);
+
+        Ok(Punctuated::<Type, Token![,]>::parse_terminated(&content)?
+            .into_iter()
+            .map(FieldPattern::Type)
+            .collect())
+    }
+
+    /// Parses the `[...]` list of groups, e.g. `[audit, meta]`
+    fn parse_groups(input: ParseStream) -> Result<Vec<FieldPattern>> {
+        let content;
+        bracketed!(content in input);
+
+        let mut patterns = Vec::new();
+
+        while !content.is_empty() {
+            if !content.peek(Ident) {
+                return Err(Error::new(
+                    content.span(),
+                    "Expected group name, e.g. `audit`",
+                ));
+            }
+
+            patterns.push(FieldPattern::Group(content.parse()?));
+
+            if !content.is_empty() {
+                content.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok(patterns)
+    }
+}
+
+impl Parse for SelectionArg {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name = input.parse::<Ident>()?;
+        input.parse::<Token![=]>()?;
+
+        match name.to_string().as_str() {
+            "includes" | "include" => Ok(SelectionArg::Includes(SelectionArg::parse_names(input)?)),
+            "excludes" | "exclude" => Ok(SelectionArg::Excludes(SelectionArg::parse_names(input)?)),
+            "types" => Ok(SelectionArg::Includes(SelectionArg::parse_types(input)?)),
+            "exclude_types" => Ok(SelectionArg::Excludes(SelectionArg::parse_types(input)?)),
+            "groups" => Ok(SelectionArg::Includes(SelectionArg::parse_groups(input)?)),
+            "exclude_groups" => Ok(SelectionArg::Excludes(SelectionArg::parse_groups(input)?)),
+            _ => Err(Error::new(
+                name.span(),
+                "Expected a field selection argument",
+            )),
+        }
+    }
+}
@@ -0,0 +1,24 @@
+//! # selection_attrs
+//! This module contains the `SelectionAttrs` struct which represents the fields selected by a struct-level attribute
+//!
+
+use crate::{field_selection::FieldPattern, selection::SelectionArg};
+
+/// Struct `SelectionAttrs` represents the fields selected by a struct-level attribute
+#[derive(Debug, Clone, Default)]
+pub struct SelectionAttrs {
+    /// Fields to include
+    pub includes: Vec<FieldPattern>,
+    /// Fields to exclude
+    pub excludes: Vec<FieldPattern>,
+}
+
+impl SelectionAttrs {
+    /// Adds the patterns of the argument
+    pub fn add(&mut self, arg: SelectionArg) {
+        match arg {
+            SelectionArg::Includes(patterns) => self.includes.extend(patterns),
+            SelectionArg::Excludes(patterns) => self.excludes.extend(patterns),
+        }
+    }
+}
@@ -0,0 +1,98 @@
+//! # shared
+//!
+//! This module contains the `SharedPointer` enum which represents an `Rc<T>` or `Arc<T>` field type,
+//! and generates getters of the pointed value
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Field, GenericArgument, Ident, PathArguments, Type};
+
+use crate::{interior::is_std_path, method_attrs::MethodAttrs};
+
+/// Reference-counted pointer of a field type
+#[derive(Debug, Clone)]
+pub enum SharedPointer {
+    /// `Rc<T>`
+    Rc(Type),
+    /// `Arc<T>`
+    Arc(Type),
+}
+
+impl SharedPointer {
+    /// Recognises the pointer by the type path, e.g. `Arc<T>` or `std::sync::Arc<T>`
+    ///
+    /// A bare name is assumed to be imported from std, a qualified path must be a std one,
+    /// so `triomphe::Arc<T>` is a regular field
+    pub fn from_type(ty: &Type) -> Option<SharedPointer> {
+        let Type::Path(path) = ty else {
+            return None;
+        };
+
+        if path.qself.is_some() {
+            return None;
+        }
+
+        let segment = path.path.segments.last()?;
+
+        let PathArguments::AngleBracketed(args) = &segment.arguments else {
+            return None;
+        };
+
+        let inner = match args.args.iter().collect::<Vec<_>>().as_slice() {
+            [GenericArgument::Type(inner)] => inner.clone(),
+            _ => return None,
+        };
+
+        match segment.ident.to_string().as_str() {
+            "Rc" if is_std_path(&path.path, &["rc"]) => Some(SharedPointer::Rc(inner)),
+            "Arc" if is_std_path(&path.path, &["sync"]) => Some(SharedPointer::Arc(inner)),
+            _ => None,
+        }
+    }
+
+    /// Generates `x()` returning the pointed value, `x_rc()`/`x_arc()` returning a cloned pointer
+    /// and `x_mut()` which clones the value if it's shared. `_mut` is replaced with `mut_suffix`
+    pub fn getters(
+        &self,
+        prefix: &str,
+        name: &Ident,
+        mut_suffix: &str,
+        field: &Field,
+        method: &MethodAttrs,
+    ) -> TokenStream {
+        let field_name = field.ident.as_ref().expect("Error, expected named field");
+        let docs = |summary: &str| method.tokens(summary, field);
+        let docs_ref = docs(&format!("Returns the `{}`", field_name));
+        let docs_clone = docs(&format!("Returns a clone of the `{}` pointer", field_name));
+        let docs_mut = docs(&format!(
+            "Returns a mutable reference to the `{}`, cloning the value if it's shared",
+            field_name
+        ));
+
+        let (ty, pointer, suffix) = match self {
+            SharedPointer::Rc(ty) => (ty, quote! { ::std::rc::Rc }, "rc"),
+            SharedPointer::Arc(ty) => (ty, quote! { ::std::sync::Arc }, "arc"),
+        };
+
+        let getter_name = format_ident!("{}{}", prefix, name);
+        let clone_name = format_ident!("{}{}_{}", prefix, name, suffix);
+        let getter_mut_name = format_ident!("{}{}{}", prefix, name, mut_suffix);
+
+        quote! {
+            #docs_ref
+            pub fn #getter_name(&self) -> &#ty {
+                &self.#field_name
+            }
+
+            #docs_clone
+            pub fn #clone_name(&self) -> #pointer<#ty> {
+                #pointer::clone(&self.#field_name)
+            }
+
+            #docs_mut
+            pub fn #getter_mut_name(&mut self) -> &mut #ty {
+                #pointer::make_mut(&mut self.#field_name)
+            }
+        }
+    }
+}
@@ -0,0 +1,45 @@
+//! # track
+//!
+//! This module contains the `Track` enum which represents the change tracking mode of setters
+
+use syn::{
+    Error, Ident, parenthesized,
+    parse::{Parse, ParseStream},
+};
+
+/// Represents the change tracking mode of setters
+#[derive(Debug, Clone, PartialEq)]
+pub enum Track {
+    /// track
+    /// Every call of a setter marks the field as dirty
+    Always,
+    /// track(eq)
+    /// A setter marks the field as dirty only if the new value is not equal to the old one
+    Eq,
+}
+
+impl Parse for Track {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident = input.fork().parse::<Ident>()?;
+
+        if ident != "track" {
+            return Err(input.error("expected `track` or `track(eq)`"));
+        }
+
+        input.parse::<Ident>()?;
+
+        if input.peek(syn::token::Paren) {
+            let content;
+            parenthesized!(content in input);
+            let mode = content.parse::<Ident>()?;
+
+            if mode != "eq" {
+                return Err(Error::new(mode.span(), "expected `eq`"));
+            }
+
+            Ok(Track::Eq)
+        } else {
+            Ok(Track::Always)
+        }
+    }
+}
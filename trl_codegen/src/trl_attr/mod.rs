@@ -0,0 +1,9 @@
+mod trl_field_arg;
+mod trl_field_attrs;
+mod trl_struct_arg;
+mod trl_struct_attrs;
+
+pub use trl_field_arg::*;
+pub use trl_field_attrs::*;
+pub use trl_struct_arg::*;
+pub use trl_struct_attrs::*;
@@ -0,0 +1,43 @@
+//! # trl_field_arg
+//! This module contains the `TrlFieldArg` enum which represents a single argument of a `#[trl]` field attribute
+//!
+
+use syn::{
+    Expr, Ident, Result, Token,
+    parse::{Parse, ParseStream},
+};
+
+use crate::helpers::unknown_arg_error;
+
+/// Names of all `#[trl]` field arguments, listed by the unknown argument error
+const ARG_NAMES: &[&str] = &["group", "default"];
+
+/// Enum TrlFieldArg represents a single argument of a `#[trl]` field attribute
+pub enum TrlFieldArg {
+    /// Group of the field, e.g. `group = audit`
+    Group(Ident),
+    /// Default value of the field, `default` or `default = expr`
+    Default(Option<Expr>),
+}
+
+impl Parse for TrlFieldArg {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name = input.parse::<Ident>()?;
+
+        match name.to_string().as_str() {
+            "group" => {
+                input.parse::<Token![=]>()?;
+
+                Ok(TrlFieldArg::Group(input.parse::<Ident>()?))
+            }
+            "default" => {
+                if input.parse::<Option<Token![=]>>()?.is_none() {
+                    return Ok(TrlFieldArg::Default(None));
+                }
+
+                Ok(TrlFieldArg::Default(Some(input.parse::<Expr>()?)))
+            }
+            _ => Err(unknown_arg_error(&name, ARG_NAMES)),
+        }
+    }
+}
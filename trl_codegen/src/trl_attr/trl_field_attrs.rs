@@ -0,0 +1,59 @@
+//! # trl_field_attrs
+//! This module contains the `TrlFieldAttrs` struct which represents the args of `#[trl]` field attributes
+//!
+
+use syn::{Expr, Field, Ident, Token, punctuated::Punctuated};
+
+use crate::{
+    attribute_parser::load_args_from_attribute, new_from_args::NewFromArgs, trl_attr::TrlFieldArg,
+};
+
+/// Struct `TrlFieldAttrs` represents the args of `#[trl(...)]` field attributes
+pub struct TrlFieldAttrs {
+    /// Groups the field belongs to, set with `group = ...`
+    pub groups: Vec<Ident>,
+    /// Default value of the field, `Some(None)` for a bare `default`
+    pub default: Option<Option<Expr>>,
+}
+
+impl TrlFieldAttrs {
+    /// Collects the args of all `#[trl]` attributes of the field
+    ///
+    /// Invalid attributes are skipped, they are reported when the field attributes are processed
+    pub fn from_field(field: &Field) -> Self {
+        let mut attrs = Self {
+            groups: Vec::new(),
+            default: None,
+        };
+
+        for parsed in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident("trl"))
+            .filter_map(|attr| load_args_from_attribute::<TrlFieldAttrs, TrlFieldArg>(attr).ok())
+        {
+            attrs.groups.extend(parsed.groups);
+            attrs.default = parsed.default.or(attrs.default);
+        }
+
+        attrs
+    }
+}
+
+impl NewFromArgs<TrlFieldArg> for TrlFieldAttrs {
+    fn new(args: Punctuated<TrlFieldArg, Token![,]>) -> Self {
+        let mut attrs = Self {
+            groups: Vec::new(),
+            default: None,
+        };
+
+        for arg in args {
+            match arg {
+                TrlFieldArg::Group(group) => attrs.groups.push(group),
+                TrlFieldArg::Default(value) => attrs.default = Some(value),
+            }
+        }
+
+        attrs
+    }
+}
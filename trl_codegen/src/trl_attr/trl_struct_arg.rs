@@ -0,0 +1,30 @@
+//! # trl_struct_arg
+//! This module contains the `TrlStructArg` enum which represents a single argument of a `#[trl]` struct attribute
+//!
+
+use syn::{
+    Ident, Result,
+    parse::{Parse, ParseStream},
+};
+
+use crate::helpers::unknown_arg_error;
+
+/// Names of all `#[trl]` struct arguments, listed by the unknown argument error
+const ARG_NAMES: &[&str] = &["default"];
+
+/// Enum TrlStructArg represents a single argument of a `#[trl]` struct attribute
+pub enum TrlStructArg {
+    /// Generate `Default` impl
+    Default,
+}
+
+impl Parse for TrlStructArg {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let name = input.parse::<Ident>()?;
+
+        match name.to_string().as_str() {
+            "default" => Ok(TrlStructArg::Default),
+            _ => Err(unknown_arg_error(&name, ARG_NAMES)),
+        }
+    }
+}
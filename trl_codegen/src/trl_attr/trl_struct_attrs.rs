@@ -0,0 +1,27 @@
+//! # trl_struct_attrs
+//! This module contains the `TrlStructAttrs` struct which represents the args of a `#[trl]` struct attribute
+//!
+
+use syn::{Token, punctuated::Punctuated};
+
+use crate::{new_from_args::NewFromArgs, trl_attr::TrlStructArg};
+
+/// Struct `TrlStructAttrs` represents the args of a `#[trl(...)]` struct attribute
+pub struct TrlStructAttrs {
+    /// Generate `Default` impl
+    pub default: bool,
+}
+
+impl NewFromArgs<TrlStructArg> for TrlStructAttrs {
+    fn new(args: Punctuated<TrlStructArg, Token![,]>) -> Self {
+        let mut attrs = Self { default: false };
+
+        for arg in args {
+            match arg {
+                TrlStructArg::Default => attrs.default = true,
+            }
+        }
+
+        attrs
+    }
+}
@@ -0,0 +1,113 @@
+//! # value_processor
+//! This module contains functions that generate `to_map`/`from_map` and the `ToValue`/`FromValue` impls
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::ItemStruct;
+
+/// Generates `to_map` and `from_map` methods
+pub fn process_value(input: &ItemStruct) -> TokenStream {
+    let mut map_inserts = TokenStream::new();
+    let mut field_reads = TokenStream::new();
+    let mut field_values = TokenStream::new();
+    let mut field_names = Vec::new();
+
+    input.fields.iter().for_each(|field| {
+        let field_name = field.ident.clone().expect("Error, expected named field");
+        let field_ty = &field.ty;
+        let key = field_name.to_string();
+        let local = format_ident!("__trl_{}", field_name);
+
+        map_inserts.extend(quote! {
+            __trl_map.insert(
+                ::std::string::String::from(#key),
+                ::trl::ToValue::to_value(&self.#field_name),
+            );
+        });
+
+        field_reads.extend(quote! {
+            let #local = match map.get(#key) {
+                ::std::option::Option::Some(value) => {
+                    match <#field_ty as ::trl::FromValue>::from_value(value) {
+                        ::std::result::Result::Ok(value) => ::std::option::Option::Some(value),
+                        ::std::result::Result::Err(e) => {
+                            __trl_error.merge_nested(#key, e);
+                            ::std::option::Option::None
+                        }
+                    }
+                }
+                // A missing key is read as `Value::Null`, so `Option` fields become `None`
+                ::std::option::Option::None => {
+                    match <#field_ty as ::trl::FromValue>::from_value(&::trl::Value::Null) {
+                        ::std::result::Result::Ok(value) => ::std::option::Option::Some(value),
+                        ::std::result::Result::Err(_) => {
+                            __trl_error.missing.push(::std::string::String::from(#key));
+                            ::std::option::Option::None
+                        }
+                    }
+                }
+            };
+        });
+
+        field_values.extend(quote! {
+            #field_name: #local.unwrap(),
+        });
+
+        field_names.push(key);
+    });
+
+    quote! {
+        /// Converts the struct into a map from field names to values
+        pub fn to_map(&self) -> ::std::collections::BTreeMap<::std::string::String, ::trl::Value> {
+            let mut __trl_map = ::std::collections::BTreeMap::new();
+            #map_inserts
+            __trl_map
+        }
+
+        /// Creates the struct from a map produced by `to_map`
+        ///
+        /// Missing, extra and mistyped keys are collected into the returned error
+        pub fn from_map(
+            map: &::std::collections::BTreeMap<::std::string::String, ::trl::Value>,
+        ) -> ::std::result::Result<Self, ::trl::FromValueError> {
+            let mut __trl_error = ::trl::FromValueError::default();
+
+            #field_reads
+
+            for key in map.keys() {
+                if ![#(#field_names),*].contains(&key.as_str()) {
+                    __trl_error.extra.push(key.clone());
+                }
+            }
+
+            if !__trl_error.is_empty() {
+                return ::std::result::Result::Err(__trl_error);
+            }
+
+            ::std::result::Result::Ok(Self { #field_values })
+        }
+    }
+}
+
+/// Generates `ToValue` and `FromValue` impls, so the struct can be nested into other `#[value]` structs
+pub fn process_value_impls(input: &ItemStruct) -> TokenStream {
+    let struct_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics ::trl::ToValue for #struct_name #ty_generics #where_clause {
+            fn to_value(&self) -> ::trl::Value {
+                ::trl::Value::Map(self.to_map())
+            }
+        }
+
+        impl #impl_generics ::trl::FromValue for #struct_name #ty_generics #where_clause {
+            fn from_value(value: &::trl::Value) -> ::std::result::Result<Self, ::trl::FromValueError> {
+                match value {
+                    ::trl::Value::Map(map) => Self::from_map(map),
+                    _ => ::std::result::Result::Err(::trl::FromValueError::mistyped("map", value)),
+                }
+            }
+        }
+    }
+}